@@ -0,0 +1,99 @@
+#ifndef __SYNAPSE_MATH_H_
+#define __SYNAPSE_MATH_H_
+
+#ifdef __cplusplus
+extern "C"
+{
+#endif
+
+#include <stdint.h>
+
+#include "synapse_math_input.h"
+
+typedef enum StatusCode
+{
+  SYNAPSE_STATUS_OK = 0,
+  SYNAPSE_STATUS_NULL_POINTER = 1,
+  SYNAPSE_STATUS_INDEX_OUT_OF_BOUNDS = 2,
+  SYNAPSE_STATUS_VECTOR_DIMENSION_MISMATCH = 3,
+  SYNAPSE_STATUS_MATRIX_INDEX_OUT_OF_BOUNDS = 4,
+  SYNAPSE_STATUS_MAT_DIMENSION_MISMATCH = 5,
+  SYNAPSE_STATUS_INSUFFICIENT_DATA = 6,
+  SYNAPSE_STATUS_INVALID_PARAMETER = 7,
+  SYNAPSE_STATUS_PANIC = 8,
+  SYNAPSE_STATUS_INVALID_INPUT = 9,
+  SYNAPSE_STATUS_IO = 10,
+  SYNAPSE_STATUS_SINGULAR_MATRIX = 11,
+  SYNAPSE_STATUS_NOT_POSITIVE_DEFINITE = 12,
+} StatusCode;
+
+typedef struct SynapseModel SynapseModel;
+
+typedef enum StopReason
+{
+  SYNAPSE_STOP_FINISHED = 0,
+  SYNAPSE_STOP_EARLY_STOP = 1,
+  SYNAPSE_STOP_DIVERGED = 2,
+  SYNAPSE_STOP_CANCELLED = 3,
+} StopReason;
+
+typedef struct SynapseTrainingReport
+{
+  uint32_t epochs_trained;
+  uint32_t history_len;
+  uint32_t best_epoch;
+  double best_val_loss;
+  double final_loss;
+  StopReason stop_reason;
+  double elapsed_seconds;
+} SynapseTrainingReport;
+
+/* Message of the last failed call on this thread, NULL if none failed yet.
+ * Owned by Rust, valid until the next failing call on the same thread */
+const char* synapse_last_error_message(void);
+
+/* Called after each epoch with user_data, return non zero to cancel training */
+typedef int32_t (*SynapseProgressFn)(uint32_t epoch, double train_loss, double val_loss, void* user_data);
+
+/* Trains a model into *out_model, release it with synapse_model_free.
+ * progress may be NULL */
+StatusCode synapse_train(const MathInput* input, SynapseProgressFn progress, void* user_data,
+                         SynapseModel** out_model);
+
+/* Threads used by the matrix kernels when MathInput.num_threads is 0,
+ * 0 uses one thread per core */
+void synapse_set_num_threads(uint32_t num_threads);
+
+/* Number of features expected per row, 0 if model is NULL */
+uint32_t synapse_model_n_features(const SynapseModel* model);
+
+/* Predict rows x cols row-major features into out[rows] */
+StatusCode synapse_model_predict(const SynapseModel* model, const double* features,
+                                 uint32_t rows, uint32_t cols, double* out);
+
+/* Writes the report of the run that trained model into *out */
+StatusCode synapse_model_training_report(const SynapseModel* model, SynapseTrainingReport* out);
+
+/* Copies the first len epochs of the loss histories, len <= history_len,
+ * train_loss or val_loss may be NULL to skip it */
+StatusCode synapse_model_loss_history(const SynapseModel* model, double* train_loss, double* val_loss,
+                                      uint32_t len);
+
+/* Saves model to the JSON file at path in the json_parser schema,
+ * run_id, model_name and target are written as given */
+StatusCode synapse_model_save_json(const SynapseModel* model, const char* path, const char* run_id,
+                                   const char* model_name, const char* target);
+
+/* Loads a model saved by synapse_model_save_json into *out_model,
+ * release it with synapse_model_free. SYNAPSE_MODEL_MULTI_LINEAR_REGRESSION
+ * models are saved as "LinearRegression", the only linear name json_parser
+ * knows, and load back as SYNAPSE_MODEL_LINEAR_REGRESSION */
+StatusCode synapse_model_load_json(const char* path, SynapseModel** out_model);
+
+void synapse_model_free(SynapseModel* model);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
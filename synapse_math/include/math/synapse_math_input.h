@@ -8,19 +8,48 @@ extern "C"
 
 #include <stdint.h>
 
+/* Layout version of MathInput, bumped whenever fields are appended */
+#define SYNAPSE_MATH_INPUT_VERSION 3
+
 typedef enum ModelType
 {
-  LinearRegression = 0,
-  MultiLinearRegression = 1,
+  SYNAPSE_MODEL_LINEAR_REGRESSION = 0,
+  SYNAPSE_MODEL_MULTI_LINEAR_REGRESSION = 1,
 } ModelType;
 
+typedef enum OptimizerKind
+{
+  SYNAPSE_OPT_SGD = 0,
+  SYNAPSE_OPT_MOMENTUM = 1,
+  SYNAPSE_OPT_ADAM = 2,
+} OptimizerKind;
+
+typedef enum LossKind
+{
+  SYNAPSE_LOSS_MSE = 0,
+  SYNAPSE_LOSS_CROSS_ENTROPY = 1,
+} LossKind;
+
+/* Element type of the feature and target buffers */
+typedef enum ElementType
+{
+  SYNAPSE_ELEMENT_F64 = 0,
+  SYNAPSE_ELEMENT_F32 = 1,
+} ElementType;
+
+/* Call synapse_math_input_init before filling the struct so struct_size,
+ * version and every optional field get their default values.
+ * Enum fields hold one of the values of the enum named next to them,
+ * anything else is rejected with SYNAPSE_STATUS_INVALID_INPUT */
 typedef struct MathInput
 {
+  uint32_t struct_size;
+  uint32_t version;
   uint32_t epochs;
   uint32_t batch_size;
   uint32_t early_stop;
   double learning_rate;
-  ModelType model_type;
+  uint32_t model_type; /* ModelType */
   uint32_t train_rows;
   uint32_t train_cols;
   const double* train_features;
@@ -29,10 +58,32 @@ typedef struct MathInput
   uint32_t test_cols;
   const double* test_features;
   const double* test_target;
+  uint32_t optimizer; /* OptimizerKind */
+  double momentum;
+  double beta1;
+  double beta2;
+  double epsilon;
+  uint32_t loss; /* LossKind */
+  uint64_t seed;
+  uint8_t use_seed; /* 0 or 1 */
+  double l1;
+  double l2;
+  double validation_fraction;
+  /* Version 2 */
+  uint32_t num_threads;
+  /* Version 3, SYNAPSE_ELEMENT_F32 inputs are read from the *_f32 pointers and
+   * trained in float, the double pointers are then ignored */
+  uint32_t element_type; /* ElementType */
+  const float* train_features_f32;
+  const float* train_target_f32;
+  const float* test_features_f32;
+  const float* test_target_f32;
 } MathInput;
 
+void synapse_math_input_init(MathInput* input);
+
 #ifdef __cplusplus
 }
 #endif
 
-#endif
\ No newline at end of file
+#endif
@@ -2,13 +2,73 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 19 May 2025
-/// Last Modified: 20 May 2025
+/// Last Modified: 17 October 2026
+
+use core::fmt;
 
 #[derive(Debug)]
 pub enum Error {
-  IndexOutOfBounds,
-  VectorDimensionMismatch,
-  MatrixIndexOutOfBounds,
-  MatDimensionMismatch,
+  IndexOutOfBounds { index: usize, len: usize },
+  /// Lengths of the two vectors that do not line up
+  VectorDimensionMismatch { left: usize, right: usize },
+  /// Row, column or entry outside a (rows, cols) matrix
+  MatrixIndexOutOfBounds { index: MatrixIndex, shape: (usize, usize) },
+  /// (rows, cols) of the two operands that do not line up,
+  /// a vector counts as a (len, 1) column
+  MatDimensionMismatch { left: (usize, usize), right: (usize, usize) },
   InsufficientData,
-}
\ No newline at end of file
+  SingularMatrix,
+  NotPositiveDefinite,
+  InvalidParameter(String),
+  InvalidInput(String),
+  NullPointer(&'static str),
+  Io(String),
+}
+
+/// Part of a matrix an index points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixIndex {
+  Row(usize),
+  Column(usize),
+  Entry(usize, usize),
+}
+
+impl fmt::Display for MatrixIndex {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      MatrixIndex::Row(row) => write!(f, "row {}", row),
+      MatrixIndex::Column(col) => write!(f, "column {}", col),
+      MatrixIndex::Entry(row, col) => write!(f, "index ({}, {})", row, col),
+    }
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let msg = match self {
+      Error::IndexOutOfBounds { index, len } => {
+        return write!(f, "vector index {} out of bounds for length {}", index, len)
+      }
+      Error::VectorDimensionMismatch { left, right } => {
+        return write!(f, "vector dimension mismatch: lengths {} and {}", left, right)
+      }
+      Error::MatrixIndexOutOfBounds { index, shape } => {
+        return write!(f, "matrix {} out of bounds for {}x{} matrix", index, shape.0, shape.1)
+      }
+      Error::MatDimensionMismatch { left, right } => {
+        return write!(f, "matrix dimension mismatch: {}x{} and {}x{}", left.0, left.1, right.0, right.1)
+      }
+      Error::InsufficientData => "insufficient or invalid data",
+      Error::SingularMatrix => "matrix is singular or nearly singular",
+      Error::NotPositiveDefinite => "matrix is not symmetric positive-definite",
+      Error::InvalidParameter(reason) => return write!(f, "invalid parameter: {}", reason),
+      Error::InvalidInput(reason) => return write!(f, "invalid input: {}", reason),
+      Error::NullPointer(name) => return write!(f, "`{}` is a null pointer", name),
+      Error::Io(reason) => return write!(f, "io error: {}", reason),
+    };
+
+    f.write_str(msg)
+  }
+}
+
+impl std::error::Error for Error {}
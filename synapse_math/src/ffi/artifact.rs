@@ -0,0 +1,110 @@
+/// ffi/artifact.rs - Math Engine FFI Model artifacts
+/// 
+/// This file defines the C entry points used to
+/// save trained models as JSON artifacts and load
+/// them back for inference
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::ffi::c_char;
+use std::ffi::CStr;
+
+use crate::error::Error;
+use crate::serialize::ModelArtifact;
+
+use super::status::{self, StatusCode};
+use super::SynapseModel;
+
+/// Borrow a non null C string as UTF-8, `name` identifies it in errors
+/// 
+/// # Safety
+/// `ptr` must point to a NUL terminated string that outlives the result
+unsafe fn utf8_arg<'a>(ptr: *const c_char, name: &'static str) -> Result<&'a str, Error> {
+  if ptr.is_null() {
+    return Err(Error::NullPointer(name));
+  }
+
+  unsafe { CStr::from_ptr(ptr) }
+    .to_str()
+    .map_err(|_| Error::InvalidInput(format!("`{}` is not valid UTF-8", name)))
+}
+
+/// Read the string arguments and write the artifact of `model`
+/// 
+/// # Safety
+/// Same as `synapse_model_save_json`
+unsafe fn save_json(
+  model: &SynapseModel,
+  path: *const c_char,
+  run_id: *const c_char,
+  model_name: *const c_char,
+  target: *const c_char,
+) -> Result<(), Error> {
+  let path = unsafe { utf8_arg(path, "path")? };
+  let run_id = unsafe { utf8_arg(run_id, "run_id")? };
+  let model_name = unsafe { utf8_arg(model_name, "model_name")? };
+  let target = unsafe { utf8_arg(target, "target")? };
+
+  model.to_artifact(run_id, model_name, target)?.save(path)
+}
+
+/// Save `model` to the JSON file at `path` in the schema read by the json_parser,
+/// with `run_id`, `model_name` and `target` describing the run
+/// 
+/// # Safety
+/// `model` must be null or a live pointer returned by `synapse_train` or
+/// `synapse_model_load_json`,
+/// the strings must be null or NUL terminated
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_model_save_json(
+  model: *const SynapseModel,
+  path: *const c_char,
+  run_id: *const c_char,
+  model_name: *const c_char,
+  target: *const c_char,
+) -> StatusCode {
+  status::guard("synapse_model_save_json", || {
+    let Some(model) = (unsafe { model.as_ref() }) else {
+      return status::report_null("model");
+    };
+
+    match unsafe { save_json(model, path, run_id, model_name, target) } {
+      Ok(()) => StatusCode::Ok,
+      Err(err) => status::report(&err, format!("saving {:?} model as JSON", model.model_type)),
+    }
+  })
+}
+
+/// Load the JSON artifact at `path` into `*out_model`, rejecting unsupported
+/// `schema_version` and `model_type` values. The model is used through
+/// `synapse_model_predict` and must be released with `synapse_model_free`
+/// 
+/// On failure `*out_model` is left untouched
+/// 
+/// # Safety
+/// `path` must be null or NUL terminated, `out_model` must be null or valid for one write
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_model_load_json(
+  path: *const c_char,
+  out_model: *mut *mut SynapseModel,
+) -> StatusCode {
+  status::guard("synapse_model_load_json", || {
+    if out_model.is_null() {
+      return status::report_null("out_model");
+    }
+    let path = match unsafe { utf8_arg(path, "path") } {
+      Ok(path) => path,
+      Err(err) => return status::report(&err, "loading model JSON"),
+    };
+
+    match ModelArtifact::load(path).and_then(SynapseModel::from_artifact) {
+      Ok(model) => {
+        unsafe { *out_model = Box::into_raw(Box::new(model)) };
+        StatusCode::Ok
+      }
+      Err(err) => status::report(&err, format!("loading model from {}", path)),
+    }
+  })
+}
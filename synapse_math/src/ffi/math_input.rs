@@ -5,27 +5,124 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 22 May 2025
-/// Last Modified: 23 May 2025
+/// Last Modified: 17 October 2026
 
-use core::slice;
+use core::{mem, ptr, slice};
 
-use crate::math::{Matrix, Vector};
+use crate::error::Error;
+use crate::linear_algebra::{LossKind, OptimizerConfig, OptimizerKind, Regularization};
+use crate::math::{Float, MatrixView, Scalar, VectorView};
+use crate::models::registry::{ModelEntry, ModelFactory};
+
+/// Layout version of MathInput, bumped whenever fields are appended
+pub const MATH_INPUT_VERSION: u32 = 3;
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ModelType {
+  #[default]
   LinearRegression = 0,
   MultiLinearRegression = 1,
 }
 
+/// Decode the `model_type` field of a MathInput
+impl TryFrom<u32> for ModelType {
+  type Error = Error;
+
+  fn try_from(value: u32) -> Result<Self, Error> {
+    match value {
+      0 => Ok(ModelType::LinearRegression),
+      1 => Ok(ModelType::MultiLinearRegression),
+      _ => Err(Error::InvalidInput(format!("unknown model type {}", value))),
+    }
+  }
+}
+
+/// Element type of the feature and target buffers of a MathInput
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ElementType {
+  #[default]
+  F64 = 0,
+  F32 = 1,
+}
+
+/// Decode the `element_type` field of a MathInput
+impl TryFrom<u32> for ElementType {
+  type Error = Error;
+
+  fn try_from(value: u32) -> Result<Self, Error> {
+    match value {
+      0 => Ok(ElementType::F64),
+      1 => Ok(ElementType::F32),
+      _ => Err(Error::InvalidInput(format!("unknown element type {}", value))),
+    }
+  }
+}
+
+/// Element type a MathInput can carry its data in, models
+/// are trained in the element type of their data
+pub trait Element: Float {
+  const ELEMENT_TYPE: ElementType;
+
+  /// Train and test (features, target) pointers of `input` holding
+  /// this type, with the name of their MathInput field
+  fn buffers(input: &MathInput) -> [(&'static str, *const Self); 4];
+  /// Factory of `entry` building models trained in this type
+  fn factory(entry: &ModelEntry) -> ModelFactory<Self>;
+}
+
+impl Element for f64 {
+  const ELEMENT_TYPE: ElementType = ElementType::F64;
+
+  fn buffers(input: &MathInput) -> [(&'static str, *const f64); 4] {
+    [
+      ("train_features", input.train_features),
+      ("train_target", input.train_target),
+      ("test_features", input.test_features),
+      ("test_target", input.test_target),
+    ]
+  }
+
+  fn factory(entry: &ModelEntry) -> ModelFactory<f64> {
+    entry.init
+  }
+}
+
+impl Element for f32 {
+  const ELEMENT_TYPE: ElementType = ElementType::F32;
+
+  fn buffers(input: &MathInput) -> [(&'static str, *const f32); 4] {
+    [
+      ("train_features_f32", input.train_features_f32),
+      ("train_target_f32", input.train_target_f32),
+      ("test_features_f32", input.test_features_f32),
+      ("test_target_f32", input.test_target_f32),
+    ]
+  }
+
+  fn factory(entry: &ModelEntry) -> ModelFactory<f32> {
+    entry.init_f32
+  }
+}
+
+/// Input filled by the AI Engine, `struct_size` and `version` must be set
+/// to the values the caller was compiled with (see `synapse_math_input_init`)
+/// so fields appended in later versions can be defaulted for older callers
+///
+/// Enum and flag fields are plain integers, any bit pattern C sends is
+/// a valid value and unknown ones are rejected when decoding them
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct MathInput {
+  pub struct_size: u32,
+  pub version: u32,
   pub epochs: u32,
   pub batch_size: u32,
   pub early_stop: u32,
   pub learning_rate: f64,
-  pub model_type: ModelType,
+  /// ModelType
+  pub model_type: u32,
   pub train_rows: u32,
   pub train_cols: u32,
   pub train_features: *const f64,
@@ -34,38 +131,305 @@ pub struct MathInput {
   pub test_cols: u32,
   pub test_features: *const f64,
   pub test_target: *const f64,
+  /// OptimizerKind
+  pub optimizer: u32,
+  pub momentum: f64,
+  pub beta1: f64,
+  pub beta2: f64,
+  pub epsilon: f64,
+  /// LossKind
+  pub loss: u32,
+  pub seed: u64,
+  /// 0 or 1
+  pub use_seed: u8,
+  pub l1: f64,
+  pub l2: f64,
+  pub validation_fraction: f64,
+  // Version 2
+  /// Threads used by the matrix kernels during training,
+  /// 0 keeps the global setting (see `synapse_set_num_threads`)
+  pub num_threads: u32,
+  // Version 3
+  /// ElementType of the data buffers, F32 inputs are read from
+  /// the `*_f32` pointers and the f64 ones are ignored
+  pub element_type: u32,
+  pub train_features_f32: *const f32,
+  pub train_target_f32: *const f32,
+  pub test_features_f32: *const f32,
+  pub test_target_f32: *const f32,
+}
+
+impl Default for MathInput {
+  fn default() -> Self {
+    let optimizer = OptimizerConfig::default();
+
+    MathInput {
+      struct_size: mem::size_of::<MathInput>() as u32,
+      version: MATH_INPUT_VERSION,
+      epochs: 100,
+      batch_size: 0,
+      early_stop: 10,
+      learning_rate: 0.01,
+      model_type: ModelType::default() as u32,
+      train_rows: 0,
+      train_cols: 0,
+      train_features: ptr::null(),
+      train_target: ptr::null(),
+      test_rows: 0,
+      test_cols: 0,
+      test_features: ptr::null(),
+      test_target: ptr::null(),
+      optimizer: optimizer.kind as u32,
+      momentum: optimizer.momentum,
+      beta1: optimizer.beta1,
+      beta2: optimizer.beta2,
+      epsilon: optimizer.eps,
+      loss: LossKind::default() as u32,
+      seed: 0,
+      use_seed: 0,
+      l1: 0.0,
+      l2: 0.0,
+      validation_fraction: 0.0,
+      num_threads: 0,
+      element_type: ElementType::default() as u32,
+      train_features_f32: ptr::null(),
+      train_target_f32: ptr::null(),
+      test_features_f32: ptr::null(),
+      test_target_f32: ptr::null(),
+    }
+  }
 }
 
-#[derive(Debug, Clone)]
-pub struct InternalInput {
+/// Training input borrowing its data, either from the
+/// C++ buffers of a MathInput or from owned Rust matrices
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InternalInput<'a, T = Scalar> {
   pub epochs: u32,
   pub batch_size: u32,
   pub early_stop: u32,
   pub learning_rate: f64,
   pub model_type: ModelType,
-  pub train_x: Matrix,
-  pub train_y: Vector,
-  pub test_x: Matrix,
-  pub test_y: Vector,
+  pub train_x: MatrixView<'a, T>,
+  pub train_y: VectorView<'a, T>,
+  pub test_x: MatrixView<'a, T>,
+  pub test_y: VectorView<'a, T>,
+  pub optimizer: OptimizerConfig,
+  pub loss: LossKind,
+  pub seed: Option<u64>,
+  pub regularization: Regularization,
+  /// Fraction of the training rows held out for validation and early
+  /// stopping, the test set is used instead when 0
+  pub validation_fraction: f64,
+  /// Threads used by the matrix kernels, 0 keeps the global setting
+  pub num_threads: u32,
+}
+
+/// Training and validation (features, targets) pairs
+pub type Split<'a, T = Scalar> = ((MatrixView<'a, T>, VectorView<'a, T>), (MatrixView<'a, T>, VectorView<'a, T>));
+
+impl<'a, T: Float> InternalInput<'a, T> {
+  /// Split the training set according to `validation_fraction`, the
+  /// validation set being the last rows so both stay borrowed views
+  pub fn validation_split(&self) -> Result<Split<'a, T>, Error> {
+    if self.validation_fraction <= 0.0 {
+      return Ok(((self.train_x, self.train_y), (self.test_x, self.test_y)));
+    }
+
+    let n = self.train_x.rows();
+    let n_val = (n as Scalar * self.validation_fraction).ceil() as usize;
+    if n_val == 0 || n_val >= n {
+      return Err(Error::InvalidParameter(format!(
+        "validation fraction {} of {} rows leaves no training or validation rows",
+        self.validation_fraction, n
+      )));
+    }
+
+    let (train_x, val_x) = self.train_x.split_at_row(n - n_val)?;
+    let (train_y, val_y) = self.train_y.split_at(n - n_val)?;
+    Ok(((train_x, train_y), (val_x, val_y)))
+  }
 }
 
 impl MathInput {
-  pub unsafe fn to_internal(&self) -> InternalInput {
+  /// Copy the MathInput pointed by `input`, reading only the fields of the
+  /// caller's version. Fields appended after it keep their default values,
+  /// even when `struct_size` covers them with the tail padding of the
+  /// caller's struct
+  ///
+  /// # Safety
+  /// `input` must be null or valid for `struct_size` bytes of reads
+  pub unsafe fn read(input: *const MathInput) -> Result<MathInput, Error> {
+    if input.is_null() {
+      return Err(Error::NullPointer("input"));
+    }
+
+    // struct_size and version lead every layout version
+    let header = unsafe { ptr::read_unaligned(input as *const [u32; 2]) };
+    let (size, version) = (header[0] as usize, header[1]);
+
+    if version == 0 || version > MATH_INPUT_VERSION {
+      return Err(Error::InvalidInput(format!(
+        "unsupported MathInput version {}, expected 1 to {}",
+        version, MATH_INPUT_VERSION
+      )));
+    }
+    if size < Self::layout_size(version) {
+      return Err(Error::InvalidInput(format!(
+        "MathInput version {} needs at least {} bytes, struct_size is {}",
+        version, Self::layout_size(version), size
+      )));
+    }
+
+    let mut out = MathInput::default();
+    unsafe {
+      ptr::copy_nonoverlapping(
+        input as *const u8,
+        &mut out as *mut MathInput as *mut u8,
+        Self::layout_size(version),
+      );
+    }
+
+    Ok(out)
+  }
+
+  /// Size in bytes of the fields of the MathInput layout of `version`,
+  /// without the tail padding C adds to it
+  fn layout_size(version: u32) -> usize {
+    match version {
+      1 => mem::offset_of!(MathInput, num_threads),
+      2 => mem::offset_of!(MathInput, element_type),
+      _ => mem::size_of::<MathInput>(),
+    }
+  }
+
+  /// Validate the C input before copying it into an InternalInput
+  /// over `T`, which must be the element type of the input
+  ///
+  /// Rejects null pointers, empty or mismatched shapes, zero epochs,
+  /// non positive learning rates, out of range optimizer, penalty or
+  /// validation parameters and NaN/Inf features or targets
+  ///
+  /// # Safety
+  /// Non null feature pointers must be valid for `rows * cols` reads
+  /// and non null target pointers for `rows` reads
+  pub unsafe fn to_internal_checked<T: Element>(&self) -> Result<InternalInput<'_, T>, Error> {
+    let element_type = ElementType::try_from(self.element_type)?;
+    if element_type != T::ELEMENT_TYPE {
+      return Err(Error::InvalidInput(format!(
+        "{:?} MathInput cannot be read as {:?}",
+        element_type, T::ELEMENT_TYPE
+      )));
+    }
+    let buffers = T::buffers(self);
+    if let Some((name, _)) = buffers.iter().find(|(_, data)| data.is_null()) {
+      return Err(Error::NullPointer(name));
+    }
+
+    if self.train_rows == 0 || self.test_rows == 0 {
+      return Err(Error::InvalidInput(format!(
+        "train and test sets need at least one row, got {} and {}",
+        self.train_rows, self.test_rows
+      )));
+    }
+    if self.train_cols == 0 {
+      return Err(Error::InvalidInput("train set has no feature columns".to_string()));
+    }
+    if self.train_cols != self.test_cols {
+      return Err(Error::InvalidInput(format!(
+        "train set has {} feature columns but test set has {}",
+        self.train_cols, self.test_cols
+      )));
+    }
+
+    if self.epochs == 0 {
+      return Err(Error::InvalidParameter("epochs must be greater than 0".to_string()));
+    }
+    if !self.learning_rate.is_finite() || self.learning_rate <= 0.0 {
+      return Err(Error::InvalidParameter(format!(
+        "learning rate must be finite and greater than 0, got {}",
+        self.learning_rate
+      )));
+    }
+    self.check_optimizer()?;
+    if !self.l1.is_finite() || !self.l2.is_finite() || self.l1 < 0.0 || self.l2 < 0.0 {
+      return Err(Error::InvalidParameter(format!(
+        "l1 and l2 strengths must be finite and >= 0, got {} and {}",
+        self.l1, self.l2
+      )));
+    }
+    if !(0.0..1.0).contains(&self.validation_fraction) {
+      return Err(Error::InvalidParameter(format!(
+        "validation fraction must be in [0, 1), got {}",
+        self.validation_fraction
+      )));
+    }
+
+    let tr = self.train_rows as usize;
+    let nr = self.test_rows as usize;
+    let cols = self.train_cols as usize;
+    let [train_features, train_target, test_features, test_target] = buffers;
+    unsafe {
+      check_finite(train_features.0, slice::from_raw_parts(train_features.1, tr * cols), cols)?;
+      check_finite(train_target.0, slice::from_raw_parts(train_target.1, tr), 1)?;
+      check_finite(test_features.0, slice::from_raw_parts(test_features.1, nr * cols), cols)?;
+      check_finite(test_target.0, slice::from_raw_parts(test_target.1, nr), 1)?;
+
+      self.to_internal()
+    }
+  }
+
+  /// Check the parameters used by the selected optimizer
+  fn check_optimizer(&self) -> Result<(), Error> {
+    let in_unit = |name: &str, value: f64| {
+      if (0.0..1.0).contains(&value) {
+        Ok(())
+      } else {
+        Err(Error::InvalidParameter(format!("{} must be in [0, 1), got {}", name, value)))
+      }
+    };
+
+    match OptimizerKind::try_from(self.optimizer)? {
+      OptimizerKind::SGD => Ok(()),
+      OptimizerKind::Momentum => in_unit("momentum", self.momentum),
+      OptimizerKind::Adam => {
+        in_unit("beta1", self.beta1)?;
+        in_unit("beta2", self.beta2)?;
+        if !self.epsilon.is_finite() || self.epsilon <= 0.0 {
+          return Err(Error::InvalidParameter(format!(
+            "epsilon must be finite and greater than 0, got {}",
+            self.epsilon
+          )));
+        }
+        Ok(())
+      }
+    }
+  }
+
+  /// View the C buffers of `T` as an InternalInput without copying
+  /// them, only decoding the enum and flag fields
+  ///
+  /// # Safety
+  /// The feature pointers of `T` must be valid for `rows * cols` reads
+  /// and its target pointers for `rows` reads, and the buffers must
+  /// outlive the returned InternalInput
+  pub unsafe fn to_internal<T: Element>(&self) -> Result<InternalInput<'_, T>, Error> {
+    let [(_, train_features), (_, train_target), (_, test_features), (_, test_target)] = T::buffers(self);
+
     let tr = self.train_rows as usize;
     let tc = self.train_cols as usize;
-    let feat_slice = unsafe { slice::from_raw_parts(self.train_features, tr * tc) };
-    let target_slice = unsafe {  slice::from_raw_parts(self.train_target, tr) };
+    let feat_slice = unsafe { slice::from_raw_parts(train_features, tr * tc) };
+    let target_slice = unsafe {  slice::from_raw_parts(train_target, tr) };
 
-    let train_x = Matrix { rows: tr, cols: tc, data: feat_slice.to_vec() };
-    let train_y = Vector { data: target_slice.to_vec() };
+    let train_x = MatrixView::new(tr, tc, feat_slice)?;
+    let train_y = VectorView { data: target_slice };
 
     let nr = self.test_rows as usize;
     let nc = self.test_cols as usize;
-    let x_slice = unsafe { slice::from_raw_parts(self.test_features, nr * nc) };
-    let y_slice = unsafe { slice::from_raw_parts(self.test_target, nr) };
+    let x_slice = unsafe { slice::from_raw_parts(test_features, nr * nc) };
+    let y_slice = unsafe { slice::from_raw_parts(test_target, nr) };
 
-    let test_x = Matrix { rows: nr, cols: nc, data: x_slice.to_vec() };
-    let test_y = Vector { data: y_slice.to_vec() };
+    let test_x = MatrixView::new(nr, nc, x_slice)?;
+    let test_y = VectorView { data: y_slice };
 
     let batch_size = if self.batch_size == 0 {
       self.train_rows
@@ -73,16 +437,59 @@ impl MathInput {
       self.batch_size
     };
 
-    InternalInput {
+    let use_seed = match self.use_seed {
+      0 => false,
+      1 => true,
+      flag => return Err(Error::InvalidInput(format!("use_seed must be 0 or 1, got {}", flag))),
+    };
+
+    Ok(InternalInput {
       epochs: self.epochs,
-      batch_size: batch_size,
+      batch_size,
       early_stop: self.early_stop,
       learning_rate: self.learning_rate,
-      model_type: self.model_type,
+      model_type: ModelType::try_from(self.model_type)?,
       train_x,
       train_y,
       test_x,
       test_y,
+      optimizer: OptimizerConfig {
+        kind: OptimizerKind::try_from(self.optimizer)?,
+        momentum: self.momentum,
+        beta1: self.beta1,
+        beta2: self.beta2,
+        eps: self.epsilon,
+      },
+      loss: LossKind::try_from(self.loss)?,
+      seed: if use_seed { Some(self.seed) } else { None },
+      regularization: Regularization { l1: self.l1, l2: self.l2 },
+      validation_fraction: self.validation_fraction,
+      num_threads: self.num_threads,
+    })
+  }
+}
+
+/// Fill `input` with the default MathInput of this library version,
+/// including its `struct_size` and `version`
+///
+/// # Safety
+/// `input` must be null or valid for one MathInput write
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_math_input_init(input: *mut MathInput) {
+  super::status::guard_or("synapse_math_input_init", (), || {
+    if let Some(input) = unsafe { input.as_mut() } {
+      *input = MathInput::default();
     }
+  })
+}
+
+/// Return an error pointing at the first NaN/Inf value of a row-major buffer
+fn check_finite<T: Float>(name: &str, data: &[T], cols: usize) -> Result<(), Error> {
+  match data.iter().position(|v| !v.is_finite()) {
+    Some(idx) => Err(Error::InvalidInput(format!(
+      "{} has non finite value {} at row {}, column {}",
+      name, data[idx], idx / cols, idx % cols
+    ))),
+    None => Ok(()),
   }
 }
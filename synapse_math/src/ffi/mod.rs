@@ -2,8 +2,18 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 22 May 2025
-/// Last Modified: 22 May 2025
+/// Last Modified: 17 October 2026
 
+pub mod artifact;
 pub mod math_input;
+pub mod model_handle;
+pub mod report;
+pub mod status;
+pub mod train;
 
-pub use math_input::{InternalInput, MathInput, ModelType};
+pub use artifact::{synapse_model_save_json, synapse_model_load_json};
+pub use math_input::{Element, ElementType, InternalInput, MathInput, ModelType, MATH_INPUT_VERSION, synapse_math_input_init};
+pub use model_handle::{SynapseModel, synapse_model_n_features, synapse_model_predict, synapse_model_free};
+pub use report::{SynapseTrainingReport, synapse_model_training_report, synapse_model_loss_history};
+pub use status::{StatusCode, synapse_last_error_message};
+pub use train::{train, synapse_train, synapse_set_num_threads, SynapseProgressFn};
@@ -0,0 +1,155 @@
+/// ffi/model_handle.rs - Math Engine FFI Model handle
+/// 
+/// This file defines the opaque handle given to C
+/// for a trained model and the functions to query,
+/// run inference with and release it
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::slice;
+
+use crate::error::Error;
+use crate::math::{MatrixView, Vector};
+use crate::models::{registry, DynModel, ModelParams};
+use crate::serialize::{ModelArtifact, SCHEMA_VERSION};
+use crate::solver::TrainingReport;
+
+use super::ModelType;
+use super::status::{self, StatusCode};
+
+/// Trained model owned by Rust, seen as an opaque pointer from C
+pub struct SynapseModel {
+  pub model_type: ModelType,
+  pub model: Box<dyn DynModel>,
+  /// Report of the run that trained `model`, only the epochs
+  /// and final loss are known for models loaded from JSON
+  pub report: TrainingReport,
+}
+
+impl SynapseModel {
+  /// Number of input features the model was trained on
+  pub fn n_features(&self) -> usize {
+    self.model.n_features()
+  }
+
+  /// Run inference on a (rows, n_features) matrix
+  pub fn predict(&self, x: MatrixView) -> Result<Vector, Error> {
+    if x.cols() != self.n_features() {
+      return Err(Error::MatDimensionMismatch { left: x.shape(), right: (self.n_features(), 1) });
+    }
+
+    self.model.predict(x)
+  }
+
+  /// Rebuild the model described by a JSON artifact, ready for inference
+  pub fn from_artifact(artifact: ModelArtifact) -> Result<Self, Error> {
+    let params = ModelParams { weights: artifact.weights, bias: artifact.bias };
+    let (model_type, model) = registry::load(&artifact.model_type, params)?;
+    let report = TrainingReport {
+      epochs_trained: artifact.epochs_trained,
+      final_loss: artifact.final_loss,
+      ..TrainingReport::default()
+    };
+
+    Ok(SynapseModel { model_type, model, report })
+  }
+
+  /// Describe the trained model and its run as a JSON artifact
+  pub fn to_artifact(&self, run_id: &str, model_name: &str, target: &str) -> Result<ModelArtifact, Error> {
+    let entry = registry::lookup(self.model_type).ok_or_else(|| {
+      Error::InvalidParameter(format!("no model registered for {:?}", self.model_type))
+    })?;
+    let params = self.model.params();
+
+    Ok(ModelArtifact {
+      schema_version: SCHEMA_VERSION.to_string(),
+      run_id: run_id.to_string(),
+      model_name: model_name.to_string(),
+      model_type: entry.name.to_string(),
+      target: target.to_string(),
+      epochs_trained: self.report.epochs_trained,
+      final_loss: self.report.final_loss,
+      weights: params.weights,
+      bias: params.bias,
+    })
+  }
+}
+
+/// Number of features expected by `synapse_model_predict`, 0 if `model` is null
+/// 
+/// # Safety
+/// `model` must be null or a live pointer returned by `synapse_train` or `synapse_model_load_json`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_model_n_features(model: *const SynapseModel) -> u32 {
+  status::guard_or("synapse_model_n_features", 0, || {
+    match unsafe { model.as_ref() } {
+      Some(model) => model.n_features() as u32,
+      None => 0,
+    }
+  })
+}
+
+/// Predict `rows` samples of `cols` features stored row-major in `features`
+/// and write one prediction per row into `out`
+/// 
+/// On failure `out` is left untouched
+/// 
+/// # Safety
+/// `model` must be null or a live pointer returned by `synapse_train` or `synapse_model_load_json`,
+/// `features` must be valid for `rows * cols` reads and `out` for `rows` writes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_model_predict(
+  model: *const SynapseModel,
+  features: *const f64,
+  rows: u32,
+  cols: u32,
+  out: *mut f64,
+) -> StatusCode {
+  status::guard("synapse_model_predict", || {
+    if model.is_null() {
+      return status::report_null("model");
+    }
+    if features.is_null() {
+      return status::report_null("features");
+    }
+    if out.is_null() {
+      return status::report_null("out");
+    }
+
+    let rows = rows as usize;
+    let cols = cols as usize;
+    let data = unsafe { slice::from_raw_parts(features, rows * cols) };
+
+    let model = unsafe { &*model };
+
+    match MatrixView::new(rows, cols, data).and_then(|x| model.predict(x)) {
+      Ok(preds) => {
+        let out = unsafe { slice::from_raw_parts_mut(out, rows) };
+        out.copy_from_slice(&preds.data);
+        StatusCode::Ok
+      }
+      Err(err) => status::report(&err, format!(
+        "predicting on {}x{} features, model expects {} features",
+        rows, cols, model.n_features(),
+      )),
+    }
+  })
+}
+
+/// Release a model returned by `synapse_train` or `synapse_model_load_json`
+/// 
+/// # Safety
+/// `model` must be null or a pointer returned by `synapse_train` or
+/// `synapse_model_load_json` that has not already been freed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_model_free(model: *mut SynapseModel) {
+  status::guard_or("synapse_model_free", (), || {
+    if model.is_null() {
+      return;
+    }
+
+    drop(unsafe { Box::from_raw(model) });
+  })
+}
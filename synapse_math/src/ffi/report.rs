@@ -0,0 +1,109 @@
+/// ffi/report.rs - Math Engine FFI Training report
+/// 
+/// This file defines the C view of the training report
+/// kept with every model and the functions to read it
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::slice;
+
+use crate::solver::{StopReason, TrainingReport};
+
+use super::status::{self, StatusCode};
+use super::SynapseModel;
+
+/// Summary of a training run, the loss curves are copied
+/// separately with `synapse_model_loss_history`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SynapseTrainingReport {
+  pub epochs_trained: u32,
+  /// Number of entries in each loss history
+  pub history_len: u32,
+  pub best_epoch: u32,
+  pub best_val_loss: f64,
+  pub final_loss: f64,
+  pub stop_reason: StopReason,
+  pub elapsed_seconds: f64,
+}
+
+impl From<&TrainingReport> for SynapseTrainingReport {
+  fn from(report: &TrainingReport) -> Self {
+    SynapseTrainingReport {
+      epochs_trained: report.epochs_trained,
+      history_len: report.train_loss.len() as u32,
+      best_epoch: report.best_epoch,
+      best_val_loss: report.best_val_loss,
+      final_loss: report.final_loss,
+      stop_reason: report.stop_reason,
+      elapsed_seconds: report.elapsed.as_secs_f64(),
+    }
+  }
+}
+
+/// Write the training report of `model` into `*out`
+/// 
+/// # Safety
+/// `model` must be null or a live pointer returned by `synapse_train` or
+/// `synapse_model_load_json`, `out` must be null or valid for one write
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_model_training_report(
+  model: *const SynapseModel,
+  out: *mut SynapseTrainingReport,
+) -> StatusCode {
+  status::guard("synapse_model_training_report", || {
+    let Some(model) = (unsafe { model.as_ref() }) else {
+      return status::report_null("model");
+    };
+    if out.is_null() {
+      return status::report_null("out");
+    }
+
+    unsafe { *out = SynapseTrainingReport::from(&model.report) };
+    StatusCode::Ok
+  })
+}
+
+/// Copy the first `len` epochs of the training and validation loss
+/// histories into `train_loss` and `val_loss`, either may be null to skip it
+/// 
+/// `len` can't exceed `history_len` from `synapse_model_training_report`,
+/// models loaded from JSON have no history
+/// 
+/// # Safety
+/// `model` must be null or a live pointer returned by `synapse_train` or
+/// `synapse_model_load_json`, non null outputs must be valid for `len` writes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_model_loss_history(
+  model: *const SynapseModel,
+  train_loss: *mut f64,
+  val_loss: *mut f64,
+  len: u32,
+) -> StatusCode {
+  status::guard("synapse_model_loss_history", || {
+    let Some(model) = (unsafe { model.as_ref() }) else {
+      return status::report_null("model");
+    };
+
+    let len = len as usize;
+    let history_len = model.report.train_loss.len();
+    if len > history_len {
+      status::set_last_error(format!(
+        "synapse_model_loss_history: asked for {} epochs, only {} recorded", len, history_len,
+      ));
+      return StatusCode::IndexOutOfBounds;
+    }
+
+    let histories = [(train_loss, &model.report.train_loss), (val_loss, &model.report.val_loss)];
+    for (out, history) in histories {
+      if !out.is_null() {
+        let out = unsafe { slice::from_raw_parts_mut(out, len) };
+        out.copy_from_slice(&history[..len]);
+      }
+    }
+
+    StatusCode::Ok
+  })
+}
@@ -0,0 +1,127 @@
+/// ffi/status.rs - Math Engine FFI Status codes
+/// 
+/// This file defines the status codes returned to C
+/// and the per thread last error message
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::ffi::c_char;
+use core::ptr;
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::error::Error;
+
+/// Spelled `SYNAPSE_STATUS_<NAME>` in synapse_math.h
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusCode {
+  Ok = 0,
+  NullPointer = 1,
+  IndexOutOfBounds = 2,
+  VectorDimensionMismatch = 3,
+  MatrixIndexOutOfBounds = 4,
+  MatDimensionMismatch = 5,
+  InsufficientData = 6,
+  InvalidParameter = 7,
+  Panic = 8,
+  InvalidInput = 9,
+  Io = 10,
+  SingularMatrix = 11,
+  NotPositiveDefinite = 12,
+}
+
+impl From<&Error> for StatusCode {
+  fn from(err: &Error) -> Self {
+    match err {
+      Error::IndexOutOfBounds { .. } => StatusCode::IndexOutOfBounds,
+      Error::VectorDimensionMismatch { .. } => StatusCode::VectorDimensionMismatch,
+      Error::MatrixIndexOutOfBounds { .. } => StatusCode::MatrixIndexOutOfBounds,
+      Error::MatDimensionMismatch { .. } => StatusCode::MatDimensionMismatch,
+      Error::InsufficientData => StatusCode::InsufficientData,
+      Error::InvalidParameter(_) => StatusCode::InvalidParameter,
+      Error::InvalidInput(_) => StatusCode::InvalidInput,
+      Error::NullPointer(_) => StatusCode::NullPointer,
+      Error::Io(_) => StatusCode::Io,
+      Error::SingularMatrix => StatusCode::SingularMatrix,
+      Error::NotPositiveDefinite => StatusCode::NotPositiveDefinite,
+    }
+  }
+}
+
+thread_local! {
+  static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Store `msg` as the last error of the calling thread
+pub fn set_last_error(msg: impl Into<String>) {
+  // Interior NULs would truncate the message on the C side anyway
+  let msg = msg.into().replace('\0', " ");
+  let msg = CString::new(msg).unwrap_or_default();
+  LAST_ERROR.with(|last| *last.borrow_mut() = Some(msg));
+}
+
+/// Record `err` with some context as the last error and return its status
+pub fn report(err: &Error, context: impl AsRef<str>) -> StatusCode {
+  set_last_error(format!("{}: {}", context.as_ref(), err));
+  StatusCode::from(err)
+}
+
+/// Record a null pointer argument as the last error
+pub fn report_null(arg: &'static str) -> StatusCode {
+  let err = Error::NullPointer(arg);
+  set_last_error(err.to_string());
+  StatusCode::from(&err)
+}
+
+/// Run the body of an exported function, turning a panic into
+/// `StatusCode::Panic` instead of unwinding into C
+pub fn guard<F>(name: &str, f: F) -> StatusCode
+where
+  F: FnOnce() -> StatusCode,
+{
+  guard_or(name, StatusCode::Panic, f)
+}
+
+/// Same as `guard` for exported functions that do not return a
+/// StatusCode, `fallback` is returned if `f` panics
+pub fn guard_or<T, F>(name: &str, fallback: T, f: F) -> T
+where
+  F: FnOnce() -> T,
+{
+  match panic::catch_unwind(AssertUnwindSafe(f)) {
+    Ok(value) => value,
+    Err(payload) => {
+      set_last_error(format!("{} panicked: {}", name, panic_message(payload.as_ref())));
+      fallback
+    }
+  }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+  if let Some(msg) = payload.downcast_ref::<&str>() {
+    msg
+  } else if let Some(msg) = payload.downcast_ref::<String>() {
+    msg
+  } else {
+    "unknown panic payload"
+  }
+}
+
+/// Message of the last failed call on this thread, or null if none failed yet
+/// 
+/// The string is owned by Rust and stays valid until the next
+/// failing call on the same thread
+#[unsafe(no_mangle)]
+pub extern "C" fn synapse_last_error_message() -> *const c_char {
+  guard_or("synapse_last_error_message", ptr::null(), || {
+    LAST_ERROR.with(|last| match last.borrow().as_ref() {
+      Some(msg) => msg.as_ptr(),
+      None => ptr::null(),
+    })
+  })
+}
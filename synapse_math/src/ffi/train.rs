@@ -0,0 +1,118 @@
+/// ffi/train.rs - Math Engine FFI Training module
+/// 
+/// This file defines the C entry point used by the
+/// AI Engine to train a model from a MathInput
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::ffi::c_void;
+
+use crate::error::Error;
+use crate::math::kernels;
+use crate::models::registry;
+use crate::solver::EpochProgress;
+
+use super::status::{self, StatusCode};
+use super::{Element, ElementType, InternalInput, MathInput, SynapseModel};
+
+/// C progress callback invoked after each epoch with the epoch number,
+/// the mean training loss and the validation loss. Returning a non zero
+/// value cancels training, the model trained so far is still returned
+pub type SynapseProgressFn = Option<
+  unsafe extern "C" fn(epoch: u32, train_loss: f64, val_loss: f64, user_data: *mut c_void) -> i32
+>;
+
+/// Train the model selected by `input.model_type` in the element type
+/// of `input`, reporting each epoch to `on_epoch` which can return
+/// false to stop training
+pub fn train<'a, T: Element>(
+  input: InternalInput<'a, T>,
+  mut on_epoch: impl FnMut(&EpochProgress) -> bool + 'a,
+) -> Result<SynapseModel, Error> {
+  kernels::with_num_threads(input.num_threads as usize, || {
+    let model = registry::create(input.model_type, &input)?;
+    let (model, report) = model.fit(input, &mut on_epoch)?;
+
+    Ok(SynapseModel { model_type: input.model_type, model, report })
+  })
+}
+
+/// Set the number of threads used by the matrix kernels for every
+/// call that doesn't set `MathInput::num_threads`, 0 uses one per core
+#[unsafe(no_mangle)]
+pub extern "C" fn synapse_set_num_threads(num_threads: u32) {
+  status::guard_or("synapse_set_num_threads", (), || {
+    kernels::set_num_threads(num_threads as usize)
+  })
+}
+
+/// Train a model and store it in `*out_model`
+/// 
+/// `progress` may be null, otherwise it is called after every epoch with
+/// `user_data` passed through untouched. On failure `*out_model` is left
+/// untouched and the reason is available through `synapse_last_error_message`.
+/// The returned model must be released with `synapse_model_free`
+/// 
+/// # Safety
+/// `input` must be null or point to a valid MathInput (see `MathInput::read`
+/// and `MathInput::to_internal_checked`),
+/// `out_model` must be null or valid for one write and `progress` must be safe
+/// to call with `user_data` from the calling thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn synapse_train(
+  input: *const MathInput,
+  progress: SynapseProgressFn,
+  user_data: *mut c_void,
+  out_model: *mut *mut SynapseModel,
+) -> StatusCode {
+  status::guard("synapse_train", || {
+    if out_model.is_null() {
+      return status::report_null("out_model");
+    }
+
+    let input = match unsafe { MathInput::read(input) } {
+      Ok(input) => input,
+      Err(err) => return status::report(&err, "reading MathInput"),
+    };
+
+    match ElementType::try_from(input.element_type) {
+      Ok(ElementType::F64) => unsafe { train_input::<f64>(&input, progress, user_data, out_model) },
+      Ok(ElementType::F32) => unsafe { train_input::<f32>(&input, progress, user_data, out_model) },
+      Err(err) => status::report(&err, "reading MathInput"),
+    }
+  })
+}
+
+/// Validate the `T` buffers of `input`, train on them and store the model in `*out_model`
+///
+/// # Safety
+/// Same as `synapse_train`, with `out_model` not null
+unsafe fn train_input<T: Element>(
+  input: &MathInput,
+  progress: SynapseProgressFn,
+  user_data: *mut c_void,
+  out_model: *mut *mut SynapseModel,
+) -> StatusCode {
+  let internal = match unsafe { input.to_internal_checked::<T>() } {
+    Ok(internal) => internal,
+    Err(err) => return status::report(&err, "validating MathInput"),
+  };
+
+  let on_epoch = |p: &EpochProgress| match progress {
+    Some(callback) => unsafe { callback(p.epoch, p.train_loss, p.val_loss, user_data) == 0 },
+    None => true,
+  };
+
+  match train(internal, on_epoch) {
+    Ok(model) => {
+      unsafe { *out_model = Box::into_raw(Box::new(model)) };
+      StatusCode::Ok
+    }
+    Err(err) => status::report(&err, format!(
+      "training {:?} on {:?} train {}x{} / test {}x{}",
+      internal.model_type, T::ELEMENT_TYPE, input.train_rows, input.train_cols, input.test_rows, input.test_cols,
+    )),
+  }
+}
@@ -5,7 +5,7 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 19 May 2025
-/// Last Modified: 21 May 2025
+/// Last Modified: 17 October 2026
 
 pub mod ffi;
 pub mod math;
@@ -15,12 +15,13 @@ pub mod error;
 pub mod models;
 pub mod solver;
 pub mod linear_algebra;
+pub mod serialize;
 
 use core::ffi::c_ulonglong;
 
 #[unsafe(no_mangle)]
 pub extern "C" fn add_rust(left: c_ulonglong, right: c_ulonglong) -> c_ulonglong {
-    left + right
+    ffi::status::guard_or("add_rust", 0, || left + right)
 }
 
 #[cfg(test)]
@@ -39,6 +40,64 @@ mod tests {
 
     use super::*;
 
+    /// y = 4·x - 2 on 20 points in [-1, 1), the fixture the FFI training tests fit
+    struct Line<T> {
+        x: Vec<T>,
+        y: Vec<T>,
+    }
+
+    impl<T: scalar::Float> Line<T> {
+        fn new() -> Line<T> {
+            let x: Vec<T> = (0..20).map(|i| T::from_f64((i as f64 - 10.0) / 10.0)).collect();
+            let y = x.iter().map(|&v| T::from_f64(4.0) * v - T::from_f64(2.0)).collect();
+            Line { x, y }
+        }
+
+        /// Full batch options validating on the training rows, without data
+        fn options(&self) -> crate::ffi::MathInput {
+            crate::ffi::MathInput {
+                epochs:        2_000,
+                early_stop:    2_000,
+                learning_rate: 0.5,
+                train_rows:    self.x.len() as u32,
+                train_cols:    1,
+                test_rows:     self.x.len() as u32,
+                test_cols:     1,
+                ..crate::ffi::MathInput::default()
+            }
+        }
+    }
+
+    impl Line<f64> {
+        fn input(&self) -> crate::ffi::MathInput {
+            crate::ffi::MathInput {
+                train_features: self.x.as_ptr(),
+                train_target:   self.y.as_ptr(),
+                test_features:  self.x.as_ptr(),
+                test_target:    self.y.as_ptr(),
+                ..self.options()
+            }
+        }
+    }
+
+    impl Line<f32> {
+        fn input(&self) -> crate::ffi::MathInput {
+            crate::ffi::MathInput {
+                element_type:       crate::ffi::ElementType::F32 as u32,
+                train_features_f32: self.x.as_ptr(),
+                train_target_f32:   self.y.as_ptr(),
+                test_features_f32:  self.x.as_ptr(),
+                test_target_f32:    self.y.as_ptr(),
+                ..self.options()
+            }
+        }
+    }
+
+    fn assert_fits_line(w: f64, b: f64) {
+        assert!((w - 4.0).abs() < 5e-2, "w ≈ 4.0, got {}", w);
+        assert!((b + 2.0).abs() < 5e-2, "b ≈ -2.0, got {}", b);
+    }
+
     #[test]
     fn it_works() {
         let result = add_rust(2, 2);
@@ -78,9 +137,8 @@ mod tests {
         assert_eq!(zero_vec.sum(), 0.0);
         assert_eq!(one_vec.sum(), 12.0);
 
-        match zero_vec.set(0, 9.8) {
-            Err(_) => println!("Error"),
-            Ok(()) => (),
+        if zero_vec.set(0, 9.8).is_err() {
+            println!("Error");
         }
         assert_eq!(zero_vec.get(0).unwrap(), 9.8);
 
@@ -99,17 +157,17 @@ mod tests {
 
     #[test]
     fn matrix_test() {
-        let zero_mat = Matrix::zeros(2, 3);
-        let one_mat = Matrix::ones(2, 3);
+        let zero_mat: Matrix = Matrix::zeros(2, 3);
+        let one_mat: Matrix = Matrix::ones(2, 3);
 
         assert_eq!(zero_mat.shape(), (2, 3));
         assert_eq!(one_mat.shape(), (2, 3));
 
-        assert_eq!(zero_mat.data, vec![0.0; 6]);
-        assert_eq!(one_mat.data, vec![1.0; 6]);
+        assert_eq!(zero_mat.data(), vec![0.0; 6]);
+        assert_eq!(one_mat.data(), vec![1.0; 6]);
 
         let mut identity_mat = Matrix::identity(3);
-        assert_eq!(identity_mat.data, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(identity_mat.data(), vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
         assert_eq!(identity_mat.shape(), (3, 3));
 
         assert_eq!(identity_mat.get(0, 0).unwrap(), 1.0);
@@ -129,9 +187,9 @@ mod tests {
         mat.set(2, 1, 6.0).unwrap();
         mat.set(2, 2, 2.8).unwrap();
 
-        assert_eq!(mat.data, vec![1.2, 3.6, 4.6, 48.6, 46.0, 26.8, 8.6, 6.0, 2.8]);
+        assert_eq!(mat.data(), vec![1.2, 3.6, 4.6, 48.6, 46.0, 26.8, 8.6, 6.0, 2.8]);
         let trans_mat = mat.transpose();
-        assert_eq!(trans_mat.data, vec![1.2, 48.6, 8.6, 3.6, 46.0, 6.0, 4.6, 26.8, 2.8]);
+        assert_eq!(trans_mat.data(), vec![1.2, 48.6, 8.6, 3.6, 46.0, 6.0, 4.6, 26.8, 2.8]);
 
         let mat1 = Matrix::ones(2, 3);
         let mut mat2 = Matrix::ones(3, 2);
@@ -141,7 +199,7 @@ mod tests {
 
         let res_mat = mat1.mat_mul(&mat2).unwrap();
 
-        assert_eq!(res_mat.data, vec![3.0, 6.0, 3.0, 6.0]);
+        assert_eq!(res_mat.data(), vec![3.0, 6.0, 3.0, 6.0]);
 
         let mut mat3 = Matrix::zeros(2, 3);
         mat3.set(0, 0, 1.0).unwrap();
@@ -194,11 +252,11 @@ mod tests {
         let mat3 = Matrix::zeros(2, 3);
         assert!(matches!(
             mat1.add(&mat3),
-            Err(Error::MatDimensionMismatch)
+            Err(Error::MatDimensionMismatch { .. })
         ));
         assert!(matches!(
             mat1.sub(&mat3),
-            Err(Error::MatDimensionMismatch)
+            Err(Error::MatDimensionMismatch { .. })
         ));
     }
 
@@ -251,7 +309,7 @@ mod tests {
     #[test]
     fn test_loss_functions() {
         // Test MSE
-        let mut pred = Vector::new(3);
+        let mut pred: Vector = Vector::new(3);
         let mut target = Vector::new(3);
         
         pred.set(0, 1.0).unwrap();
@@ -272,7 +330,7 @@ mod tests {
         assert!((grad.get(2).unwrap() + 2.0).abs() < 1e-6);
 
         // Test Cross Entropy
-        let mut pred_ce = Vector::new(2);
+        let mut pred_ce: Vector = Vector::new(2);
         let mut target_ce = Vector::new(2);
         
         pred_ce.set(0, 0.8).unwrap();
@@ -291,19 +349,19 @@ mod tests {
         assert!((grad_ce.get(1).unwrap()).abs() < 1e-6);
 
         // Test Error Cases
-        let v1 = Vector::new(2);
+        let v1: Vector = Vector::new(2);
         let v2 = Vector::new(3);
         
         // Test dimension mismatch
-        assert!(matches!(MSE::loss(&v1, &v2), Err(Error::VectorDimensionMismatch)));
-        assert!(matches!(MSE::grad(&v1, &v2), Err(Error::VectorDimensionMismatch)));
-        assert!(matches!(CrossEntropy::loss(&v1, &v2), Err(Error::VectorDimensionMismatch)));
-        assert!(matches!(CrossEntropy::grad(&v1, &v2), Err(Error::VectorDimensionMismatch)));
+        assert!(matches!(MSE::loss(&v1, &v2), Err(Error::VectorDimensionMismatch { .. })));
+        assert!(matches!(MSE::grad(&v1, &v2), Err(Error::VectorDimensionMismatch { .. })));
+        assert!(matches!(CrossEntropy::loss(&v1, &v2), Err(Error::VectorDimensionMismatch { .. })));
+        assert!(matches!(CrossEntropy::grad(&v1, &v2), Err(Error::VectorDimensionMismatch { .. })));
 
         // Test empty vectors
-        let empty_vec = Vector::new(0);
-        assert!(matches!(MSE::loss(&empty_vec, &empty_vec), Err(Error::VectorDimensionMismatch)));
-        assert!(matches!(MSE::grad(&empty_vec, &empty_vec), Err(Error::VectorDimensionMismatch)));
+        let empty_vec: Vector = Vector::new(0);
+        assert!(matches!(MSE::loss(&empty_vec, &empty_vec), Err(Error::VectorDimensionMismatch { .. })));
+        assert!(matches!(MSE::grad(&empty_vec, &empty_vec), Err(Error::VectorDimensionMismatch { .. })));
 
         // Test invalid inputs for cross entropy (predictions <= 0)
         let mut invalid_pred = Vector::new(2);
@@ -325,34 +383,34 @@ mod tests {
         assert_eq!(ReLU::backward(2.0), 1.0);
 
         // Test Sigmoid
-        assert!((Sigmoid::forward(0.0) - 0.5).abs() < 1e-6);
-        assert!((Sigmoid::forward(2.0) - 0.880797).abs() < 1e-6);
-        assert!((Sigmoid::forward(-2.0) - 0.119203).abs() < 1e-6);
+        assert!((Sigmoid::forward(0.0_f64) - 0.5).abs() < 1e-6);
+        assert!((Sigmoid::forward(2.0_f64) - 0.880797).abs() < 1e-6);
+        assert!((Sigmoid::forward(-2.0_f64) - 0.119203).abs() < 1e-6);
 
-        assert!((Sigmoid::backward(0.0) - 0.25).abs() < 1e-6);
-        assert!((Sigmoid::backward(2.0) - 0.104994).abs() < 1e-6);
-        assert!((Sigmoid::backward(-2.0) - 0.104994).abs() < 1e-6);
+        assert!((Sigmoid::backward(0.0_f64) - 0.25).abs() < 1e-6);
+        assert!((Sigmoid::backward(2.0_f64) - 0.104994).abs() < 1e-6);
+        assert!((Sigmoid::backward(-2.0_f64) - 0.104994).abs() < 1e-6);
 
         // Test Tanh
         assert_eq!(Tanh::forward(0.0), 0.0);
-        assert!((Tanh::forward(1.0) - 0.761594).abs() < 1e-6);
-        assert!((Tanh::forward(-1.0) + 0.761594).abs() < 1e-6);
+        assert!((Tanh::forward(1.0_f64) - 0.761594).abs() < 1e-6);
+        assert!((Tanh::forward(-1.0_f64) + 0.761594).abs() < 1e-6);
 
         assert_eq!(Tanh::backward(0.0), 1.0);
-        assert!((Tanh::backward(1.0) - 0.419974).abs() < 1e-6);
-        assert!((Tanh::backward(-1.0) - 0.419974).abs() < 1e-6);
+        assert!((Tanh::backward(1.0_f64) - 0.419974).abs() < 1e-6);
+        assert!((Tanh::backward(-1.0_f64) - 0.419974).abs() < 1e-6);
 
         // Test edge cases
-        assert!((Sigmoid::forward(10.0) - 1.0).abs() < 1e-4); // Very large input
-        assert!((Sigmoid::forward(-10.0)).abs() < 1e-4);      // Very small input
-        assert!((Tanh::forward(10.0) - 1.0).abs() < 1e-4);   // Very large input
-        assert!((Tanh::forward(-10.0) + 1.0).abs() < 1e-4);  // Very small input
+        assert!((Sigmoid::forward(10.0_f64) - 1.0).abs() < 1e-4); // Very large input
+        assert!((Sigmoid::forward(-10.0_f64)).abs() < 1e-4);      // Very small input
+        assert!((Tanh::forward(10.0_f64) - 1.0).abs() < 1e-4);   // Very large input
+        assert!((Tanh::forward(-10.0_f64) + 1.0).abs() < 1e-4);  // Very small input
     }
 
     #[test]
     fn test_optimizers() {
         // Test function: f(x) = x^2, gradient = 2x
-        let mut params = Vector::new(1);
+        let mut params: Vector = Vector::new(1);
         params.set(0, 2.0).unwrap();  // Start at x = 2
 
         // Test SGD
@@ -385,6 +443,10 @@ mod tests {
             }
             
             assert!(p.get(0).unwrap().abs() < 0.8);
+            assert!(matches!(
+                momentum.update(&mut p, &Vector::zeroes(2)),
+                Err(Error::VectorDimensionMismatch { left: 1, right: 2 })
+            ));
         }
 
         // Test Adam
@@ -415,20 +477,26 @@ mod tests {
         let mut rng = Random::new(Some(42)); // Use seed for deterministic tests
         
         // Test uniform scalar
-        let u = rng.uniform_scalar(0.0, 1.0);
-        assert!(u >= 0.0 && u <= 1.0);
+        let u = rng.uniform_scalar(0.0, 1.0).unwrap();
+        assert!((0.0..=1.0).contains(&u));
 
         // Test normal scalar
-        let n = rng.normal_scalar(0.0, 1.0);
+        let n = rng.normal_scalar(0.0, 1.0).unwrap();
         assert!(!n.is_nan() && !n.is_infinite());
 
         // Test vector generation
-        let v = rng.uniform_vector(10, 0.0, 1.0);
+        let v = rng.uniform_vector(10, 0.0, 1.0).unwrap();
         assert_eq!(v.len(), 10);
         
         // Test matrix generation
-        let m = rng.normal_matrix(2, 3, 0.0, 1.0);
+        let m = rng.normal_matrix(2, 3, 0.0, 1.0).unwrap();
         assert_eq!(m.shape(), (2, 3));
+
+        // Invalid distribution parameters are errors, not panics
+        assert!(matches!(rng.normal_scalar(0.0, -1.0), Err(Error::InvalidParameter(_))));
+        assert!(matches!(rng.normal_scalar(f64::NAN, 1.0), Err(Error::InvalidParameter(_))));
+        assert!(matches!(rng.normal_scalar(f64::INFINITY, 1.0), Err(Error::InvalidParameter(_))));
+        assert!(matches!(rng.uniform_scalar(1.0, 0.0), Err(Error::InvalidParameter(_))));
     }
 
     #[test]
@@ -482,11 +550,7 @@ mod tests {
             .collect();
 
         // build training matrices/vectors
-        let train_x = Matrix {
-            rows: normalized_celsius.len(),
-            cols: 1,
-            data: normalized_celsius.clone(),
-        };
+        let train_x = Matrix::from_vec(normalized_celsius.len(), 1, normalized_celsius.clone()).unwrap();
         let train_y = Vector {
             data: normalized_fahrenheit.clone(),
         };
@@ -500,14 +564,15 @@ mod tests {
             early_stop:    1_000,
             learning_rate: 0.1,
             model_type:    ModelType::LinearRegression,
-            train_x,
-            train_y,
-            test_x,
-            test_y,
+            train_x:       train_x.view(),
+            train_y:       train_y.view(),
+            test_x:        test_x.view(),
+            test_y:        test_y.view(),
+            ..InternalInput::default()
         };
 
         // train the model
-        let mut model = LinearRegression::init(&input);
+        let mut model = LinearRegression::init(&input)?;
         model.train(&input)?;
 
         // Extract and un-scale the learned weight & bias ---
@@ -560,8 +625,8 @@ mod tests {
         use crate::linear_algebra::MSE;
 
         // y = 2·x1 + 3·x2 + 1 over 5 points
-        let x1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-        let x2 = vec![5.0, 4.0, 3.0, 2.0, 1.0];
+        let x1 = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let x2 = [5.0, 4.0, 3.0, 2.0, 1.0];
         let mut data = Vec::with_capacity(x1.len() * 2);
         for i in 0..x1.len() {
             data.push(x1[i]);
@@ -574,7 +639,7 @@ mod tests {
             .collect();
 
         let n = x1.len();
-        let train_x = Matrix { rows: n, cols: 2, data: data.clone() };
+        let train_x = Matrix::from_vec(n, 2, data.clone()).unwrap();
         let train_y = Vector { data: y.clone() };
         let test_x  = train_x.clone();
         let test_y  = train_y.clone();
@@ -585,13 +650,14 @@ mod tests {
             early_stop:    1_000,
             learning_rate: 1e-2,
             model_type:    ModelType::LinearRegression,
-            train_x,
-            train_y,
-            test_x,
-            test_y,
+            train_x:       train_x.view(),
+            train_y:       train_y.view(),
+            test_x:        test_x.view(),
+            test_y:        test_y.view(),
+            ..InternalInput::default()
         };
 
-        let mut model = LinearRegression::init(&input);
+        let mut model = LinearRegression::init(&input)?;
         model.train(&input)?;
 
         let w1 = model.weights.get(0).unwrap();
@@ -603,10 +669,1097 @@ mod tests {
         assert!((w2 - 3.0).abs() < 5e-2, "w2 ≈ 3.0, got {}", w2);
         assert!((b  - 1.0).abs() < 2e-1, "b  ≈ 1.0, got {}", b);
 
-        let preds = model.predict(input.test_x.clone())?;
-        let mse   = MSE::loss(&preds, &input.test_y)?;
+        let preds = model.predict(input.test_x)?;
+        let mse   = MSE::loss(&preds, input.test_y)?;
         assert!(mse < 1e-2, "final MSE too large: {}", mse);
 
         Ok(())
     }
+
+    #[test]
+    fn ffi_train_linear_regression() {
+        use crate::ffi::{synapse_model_free, synapse_model_n_features, synapse_model_predict, synapse_train};
+        use crate::ffi::{synapse_last_error_message, StatusCode, SynapseModel};
+        use crate::models::linear_regression::LinearRegression;
+        use std::ffi::CStr;
+
+        let line = Line::<f64>::new();
+        let input = line.input();
+
+        let mut handle = core::ptr::null_mut();
+        let status = unsafe { synapse_train(&input, None, core::ptr::null_mut(), &mut handle) };
+        assert_eq!(status, StatusCode::Ok);
+        assert!(!handle.is_null());
+
+        let trained: &SynapseModel = unsafe { &*handle };
+        let model = trained.model.as_any().downcast_ref::<LinearRegression>().unwrap();
+        assert_fits_line(model.weights[0], model.bias);
+
+        assert_eq!(unsafe { synapse_model_n_features(handle) }, 1);
+
+        let new_rows = [0.5, 1.0];
+        let mut out = [0.0; 2];
+        let status = unsafe { synapse_model_predict(handle, new_rows.as_ptr(), 2, 1, out.as_mut_ptr()) };
+        assert_eq!(status, StatusCode::Ok);
+        assert!((out[0] - 0.0).abs() < 5e-2, "predict(0.5) ≈ 0.0, got {}", out[0]);
+        assert!((out[1] - 2.0).abs() < 5e-2, "predict(1.0) ≈ 2.0, got {}", out[1]);
+
+        // Feature count mismatch leaves the output untouched
+        let status = unsafe { synapse_model_predict(handle, new_rows.as_ptr(), 1, 2, out.as_mut_ptr()) };
+        assert_eq!(status, StatusCode::MatDimensionMismatch);
+        let msg = unsafe { CStr::from_ptr(synapse_last_error_message()) }.to_str().unwrap();
+        assert!(msg.contains("1x2") && msg.contains("expects 1"), "unexpected message: {}", msg);
+        assert!(msg.ends_with("matrix dimension mismatch: 1x2 and 1x1"), "unexpected message: {}", msg);
+        let short = Vector::<f64>::zeroes(3).dot(&Vector::zeroes(2)).unwrap_err();
+        assert_eq!(short.to_string(), "vector dimension mismatch: lengths 3 and 2");
+
+        unsafe { synapse_model_free(handle) };
+
+        let mut handle = core::ptr::null_mut();
+        let status = unsafe { synapse_train(core::ptr::null(), None, core::ptr::null_mut(), &mut handle) };
+        assert_eq!(status, StatusCode::NullPointer);
+        assert!(handle.is_null());
+    }
+
+    #[test]
+    fn ffi_panics_are_contained() {
+        use crate::ffi::status::guard;
+        use crate::ffi::{synapse_last_error_message, StatusCode};
+        use crate::solver::batches;
+        use std::ffi::CStr;
+
+        let status = guard("boom_fn", || panic!("boom"));
+        assert_eq!(status, StatusCode::Panic);
+        let msg = unsafe { CStr::from_ptr(synapse_last_error_message()) }.to_str().unwrap();
+        assert_eq!(msg, "boom_fn panicked: boom");
+
+        // Zero sized batches used to panic inside `chunks`
+        let x: Matrix = Matrix::ones(4, 2);
+        let y: Vector = Vector::ones(4);
+        let mut rng = Random::new(Some(7));
+        assert!(matches!(batches(x.view(), y.view(), 0, &mut rng), Err(Error::InvalidParameter(_))));
+        assert_eq!(batches(x.view(), y.view(), 3, &mut rng).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn math_input_validation() {
+        use crate::ffi::{MathInput, ModelType};
+
+        let mut x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let y = [1.0, 2.0, 3.0];
+        let valid = MathInput {
+            epochs:         10,
+            batch_size:     0,
+            early_stop:     5,
+            learning_rate:  0.1,
+            model_type:     ModelType::LinearRegression as u32,
+            train_rows:     3,
+            train_cols:     2,
+            train_features: x.as_ptr(),
+            train_target:   y.as_ptr(),
+            test_rows:      3,
+            test_cols:      2,
+            test_features:  x.as_ptr(),
+            test_target:    y.as_ptr(),
+            ..MathInput::default()
+        };
+
+        let internal = unsafe { valid.to_internal_checked::<f64>() }.unwrap();
+        assert_eq!(internal.train_x.shape(), (3, 2));
+        assert_eq!(internal.batch_size, 3);
+        assert!(core::ptr::eq(internal.train_x.data().as_ptr(), x.as_ptr()));
+
+        let null_target = MathInput { test_target: core::ptr::null(), ..valid };
+        assert!(matches!(unsafe { null_target.to_internal_checked::<f64>() }, Err(Error::NullPointer("test_target"))));
+
+        let mismatched = MathInput { test_rows: 2, test_cols: 3, ..valid };
+        assert!(matches!(unsafe { mismatched.to_internal_checked::<f64>() }, Err(Error::InvalidInput(_))));
+
+        let no_rows = MathInput { train_rows: 0, ..valid };
+        assert!(matches!(unsafe { no_rows.to_internal_checked::<f64>() }, Err(Error::InvalidInput(_))));
+
+        let no_epochs = MathInput { epochs: 0, ..valid };
+        assert!(matches!(unsafe { no_epochs.to_internal_checked::<f64>() }, Err(Error::InvalidParameter(_))));
+
+        let bad_lr = MathInput { learning_rate: 0.0, ..valid };
+        assert!(matches!(unsafe { bad_lr.to_internal_checked::<f64>() }, Err(Error::InvalidParameter(_))));
+
+        // Enum and flag fields arrive from C as plain integers
+        for unknown in [
+            MathInput { model_type: 7, ..valid },
+            MathInput { optimizer: 3, ..valid },
+            MathInput { loss: 2, ..valid },
+            MathInput { use_seed: 2, ..valid },
+            MathInput { element_type: 9, ..valid },
+        ] {
+            assert!(matches!(unsafe { unknown.to_internal_checked::<f64>() }, Err(Error::InvalidInput(_))));
+        }
+
+        x[5] = f64::NAN;
+        let with_nan = MathInput { train_features: x.as_ptr(), ..valid };
+        match unsafe { with_nan.to_internal_checked::<f64>() } {
+            Err(Error::InvalidInput(msg)) => assert!(msg.contains("row 2, column 1"), "unexpected message: {}", msg),
+            other => panic!("expected InvalidInput, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn matrix_vector_views() {
+        use crate::math::{MatrixView, VectorView};
+
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert!(matches!(MatrixView::new(4, 2, &data), Err(Error::MatDimensionMismatch { .. })));
+
+        let view = MatrixView::new(2, 3, &data).unwrap();
+        assert_eq!(view.shape(), (2, 3));
+        assert_eq!(view.get(1, 2), Some(6.0));
+        assert_eq!(view.row(1), Some(&data[3..6]));
+        assert_eq!(view.transpose().data(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+
+        let weights = [1.0, 2.0, 3.0];
+        let from_view = view.vec_mul(VectorView::new(&weights)).unwrap();
+        let from_matrix = view.to_matrix().vec_mul(&Vector { data: weights.to_vec() }).unwrap();
+        assert_eq!(from_view.data, vec![14.0, 32.0]);
+        assert_eq!(from_view.data, from_matrix.data);
+
+        // Views borrow the caller's buffer instead of copying it
+        assert!(core::ptr::eq(view.data(), &data[..]));
+        assert_eq!(VectorView::new(&weights).dot(VectorView::new(&weights)).unwrap(), 14.0);
+    }
+
+    #[test]
+    fn ffi_progress_callback_cancels() {
+        use crate::ffi::{synapse_model_free, synapse_train, MathInput, ModelType, StatusCode};
+        use core::ffi::c_void;
+
+        unsafe extern "C" fn record(epoch: u32, train_loss: f64, val_loss: f64, user_data: *mut c_void) -> i32 {
+            let seen = unsafe { &mut *(user_data as *mut Vec<(u32, f64, f64)>) };
+            seen.push((epoch, train_loss, val_loss));
+            // Request cancellation after the 5th epoch
+            (epoch >= 5) as i32
+        }
+
+        let x: Vec<f64> = (0..10).map(|i| i as f64 / 10.0).collect();
+        let y: Vec<f64> = x.iter().map(|&v| 3.0 * v).collect();
+        let input = MathInput {
+            epochs:         100,
+            batch_size:     4,
+            early_stop:     100,
+            learning_rate:  0.1,
+            model_type:     ModelType::LinearRegression as u32,
+            train_rows:     10,
+            train_cols:     1,
+            train_features: x.as_ptr(),
+            train_target:   y.as_ptr(),
+            test_rows:      10,
+            test_cols:      1,
+            test_features:  x.as_ptr(),
+            test_target:    y.as_ptr(),
+            ..MathInput::default()
+        };
+
+        let mut seen: Vec<(u32, f64, f64)> = Vec::new();
+        let mut handle = core::ptr::null_mut();
+        let user_data = &mut seen as *mut Vec<(u32, f64, f64)> as *mut c_void;
+        let status = unsafe { synapse_train(&input, Some(record), user_data, &mut handle) };
+        assert_eq!(status, StatusCode::Ok);
+        assert!(!handle.is_null());
+
+        let epochs: Vec<u32> = seen.iter().map(|&(e, _, _)| e).collect();
+        assert_eq!(epochs, vec![1, 2, 3, 4, 5]);
+        assert!(seen.iter().all(|&(_, t, v)| t.is_finite() && v.is_finite()));
+        assert!(seen[4].2 < seen[0].2, "validation loss should decrease");
+
+        unsafe { synapse_model_free(handle) };
+    }
+
+    #[test]
+    fn math_input_versioned_read() {
+        use crate::ffi::{MathInput, MATH_INPUT_VERSION};
+
+        let input = MathInput { epochs: 42, l2: 0.5, ..MathInput::default() };
+        let read = unsafe { MathInput::read(&input) }.unwrap();
+        assert_eq!(read.epochs, 42);
+        assert_eq!(read.l2, 0.5);
+        assert_eq!(read.version, MATH_INPUT_VERSION);
+
+        let future = MathInput { version: MATH_INPUT_VERSION + 1, ..input };
+        assert!(matches!(unsafe { MathInput::read(&future) }, Err(Error::InvalidInput(_))));
+
+        let truncated = MathInput { struct_size: 16, ..input };
+        assert!(matches!(unsafe { MathInput::read(&truncated) }, Err(Error::InvalidInput(_))));
+
+        assert!(matches!(unsafe { MathInput::read(core::ptr::null()) }, Err(Error::NullPointer("input"))));
+    }
+
+    #[test]
+    fn solver_honours_training_options() -> Result<(), crate::error::Error> {
+        use crate::ffi::InternalInput;
+        use crate::linear_algebra::{LossKind, OptimizerConfig, OptimizerKind, Regularization};
+        use crate::models::linear_regression::LinearRegression;
+        use crate::solver::Solver;
+
+        // y = 2·x + 1
+        let x: Vec<Scalar> = (0..40).map(|i| (i as Scalar - 20.0) / 10.0).collect();
+        let y: Vec<Scalar> = x.iter().map(|&v| 2.0 * v + 1.0).collect();
+        let train_x = Matrix::from_vec(x.len(), 1, x.clone()).unwrap();
+        let train_y = Vector { data: y.clone() };
+
+        let input = InternalInput {
+            epochs:         300,
+            batch_size:     8,
+            early_stop:     300,
+            learning_rate:  0.05,
+            train_x:        train_x.view(),
+            train_y:        train_y.view(),
+            test_x:         train_x.view(),
+            test_y:         train_y.view(),
+            optimizer:      OptimizerConfig { kind: OptimizerKind::Adam, ..OptimizerConfig::default() },
+            loss:           LossKind::MSE,
+            seed:           Some(1234),
+            ..InternalInput::default()
+        };
+
+        // Same seed, same initialization and batch order
+        let mut first = Solver::<LinearRegression>::new(input)?;
+        let mut second = Solver::<LinearRegression>::new(input)?;
+        first.train()?;
+        second.train()?;
+        assert_eq!(first.model.weights.data, second.model.weights.data);
+        assert_eq!(first.model.bias, second.model.bias);
+
+        let w = first.model.weights.get(0).unwrap();
+        assert!((w - 2.0).abs() < 5e-2, "adam w ≈ 2.0, got {}", w);
+        assert!((first.model.bias - 1.0).abs() < 5e-2, "adam b ≈ 1.0, got {}", first.model.bias);
+
+        // A strong L2 penalty shrinks the slope towards 0
+        let ridge = InternalInput { regularization: Regularization { l1: 0.0, l2: 1.0 }, ..input };
+        let mut shrunk = Solver::<LinearRegression>::new(ridge)?;
+        shrunk.train()?;
+        let w_ridge = shrunk.model.weights.get(0).unwrap();
+        assert!(w_ridge < w - 0.5, "ridge slope {} should be well below {}", w_ridge, w);
+
+        // Validation rows are carved from the end of the training set
+        let held_out = InternalInput { validation_fraction: 0.25, ..input };
+        let ((tx, ty), (vx, vy)) = held_out.validation_split()?;
+        assert_eq!((tx.rows(), ty.len(), vx.rows(), vy.len()), (30, 30, 10, 10));
+        assert_eq!(vx.get(0, 0), Some(x[30]));
+        let too_big = InternalInput { validation_fraction: 0.999, ..input };
+        assert!(matches!(too_big.validation_split(), Err(Error::InvalidParameter(_))));
+
+        // Linear outputs are unbounded, cross entropy is refused before training
+        let entropy = InternalInput { loss: LossKind::CrossEntropy, ..input };
+        let created = crate::models::registry::create(entropy.model_type, &entropy);
+        assert!(matches!(created, Err(Error::InvalidParameter(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn registry_builds_models_at_runtime() -> Result<(), crate::error::Error> {
+        use crate::ffi::{InternalInput, ModelType};
+        use crate::models::{registry, ModelParams};
+
+        // y = x1 - x2
+        let data = vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 2.0, 2.0, 2.0];
+        let x = Matrix::from_vec(6, 2, data).unwrap();
+        let y = Vector { data: vec![1.0, -1.0, 0.0, 1.0, -1.0, 0.0] };
+
+        for model_type in [ModelType::LinearRegression, ModelType::MultiLinearRegression] {
+            let input = InternalInput {
+                epochs:        2_000,
+                batch_size:    6,
+                early_stop:    2_000,
+                learning_rate: 0.1,
+                model_type,
+                train_x:       x.view(),
+                train_y:       y.view(),
+                test_x:        x.view(),
+                test_y:        y.view(),
+                ..InternalInput::default()
+            };
+
+            assert!(registry::lookup(model_type).is_some());
+            let model = registry::create(model_type, &input)?;
+            assert_eq!(model.n_features(), 2);
+
+            let mut epochs = 0;
+            let (model, _) = model.fit(input, &mut |_| { epochs += 1; true })?;
+            assert!(epochs > 0);
+
+            let preds = model.predict(x.view())?;
+            assert!(MSE::loss(&preds, &y)? < 1e-3, "{:?} did not fit", model_type);
+        }
+
+        // fit trains the model it is given instead of a fresh one
+        let exact = ModelParams { weights: Vector { data: vec![1.0, -1.0] }, bias: 0.0 };
+        let (_, model) = registry::load("LinearRegression", exact.clone())?;
+        let mut first_loss = f64::NAN;
+        let input = InternalInput {
+            epochs:     1,
+            batch_size: 6,
+            train_x:    x.view(),
+            train_y:    y.view(),
+            test_x:     x.view(),
+            test_y:     y.view(),
+            ..InternalInput::default()
+        };
+        model.fit(input, &mut |p| { first_loss = p.train_loss; true })?;
+        assert!(first_loss < 1e-12, "started from scratch, loss {}", first_loss);
+
+        let wide = ModelParams { weights: Vector::ones(3), ..exact };
+        let (_, model) = registry::load("LinearRegression", wide)?;
+        let err = model.fit(input, &mut |_| true).err().unwrap();
+        assert!(matches!(err, Error::MatDimensionMismatch { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_model_artifact() {
+        use crate::ffi::{synapse_model_free, synapse_model_save_json, train};
+        use crate::ffi::{MathInput, ModelType, StatusCode};
+        use crate::serialize::ModelArtifact;
+        use std::ffi::CString;
+
+        let line = Line::<f64>::new();
+        let input = MathInput { model_type: ModelType::MultiLinearRegression as u32, ..line.input() };
+
+        // Cancelled after 40 of the 2000 requested epochs
+        let internal = unsafe { input.to_internal_checked::<f64>() }.unwrap();
+        let trained = train(internal, |p| p.epoch < 40).unwrap();
+        let test_loss = MSE::loss(&trained.predict(internal.test_x).unwrap(), internal.test_y).unwrap();
+
+        let artifact = trained.to_artifact("run_1", "Line \"fit\"", "y").unwrap();
+        assert_eq!(artifact.schema_version, "0.1.0");
+        assert_eq!(artifact.model_type, "LinearRegression");
+        assert_eq!(artifact.epochs_trained, 40);
+        assert_eq!(artifact.final_loss, test_loss);
+
+        let json = artifact.to_json().unwrap();
+        assert!(json.starts_with("{\n  \"schema_version\": \"0.1.0\",\n  \"run_id\": \"run_1\",\n"));
+        assert!(json.contains("\"model_name\": \"Line \\\"fit\\\"\",\n"));
+        assert!(json.contains(&format!("\"epochs_trained\": {},\n", artifact.epochs_trained)));
+        assert!(json.contains(&format!("\"weights\": [{}],\n", artifact.weights.data[0])));
+        assert!(json.ends_with(&format!("\"bias\": {}\n}}\n", artifact.bias)));
+
+        let path = std::env::temp_dir().join(format!("synapse_export_{}.json", std::process::id()));
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        let run_id = CString::new("run_1").unwrap();
+        let name = CString::new("Line \"fit\"").unwrap();
+        let target = CString::new("y").unwrap();
+        let handle = Box::into_raw(Box::new(trained));
+        let status = unsafe {
+            synapse_model_save_json(handle, c_path.as_ptr(), run_id.as_ptr(), name.as_ptr(), target.as_ptr())
+        };
+        assert_eq!(status, StatusCode::Ok);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), json);
+        std::fs::remove_file(&path).unwrap();
+
+        let status = unsafe {
+            synapse_model_save_json(handle, c_path.as_ptr(), core::ptr::null(), name.as_ptr(), target.as_ptr())
+        };
+        assert_eq!(status, StatusCode::NullPointer);
+        unsafe { synapse_model_free(handle) };
+
+        // NaN has no JSON representation
+        let broken = ModelArtifact { final_loss: f64::NAN, ..artifact };
+        assert!(matches!(broken.to_json(), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn load_model_artifact() {
+        use crate::ffi::{synapse_model_free, synapse_model_load_json, synapse_model_predict};
+        use crate::ffi::{ModelType, StatusCode, SynapseModel};
+        use crate::models::registry;
+        use crate::serialize::{json, ModelArtifact};
+        use std::ffi::CString;
+
+        // Copy of the sample artifact read by the AI Engine
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/model.json");
+        let c_path = CString::new(path).unwrap();
+        let mut handle = core::ptr::null_mut();
+        let status = unsafe { synapse_model_load_json(c_path.as_ptr(), &mut handle) };
+        assert_eq!(status, StatusCode::Ok);
+
+        let loaded: &SynapseModel = unsafe { &*handle };
+        assert_eq!(loaded.model_type, ModelType::LinearRegression);
+        assert_eq!(loaded.report.epochs_trained, 10);
+
+        // MultiLinearRegression is exported under the same name and loads back as LinearRegression
+        let multi = SynapseModel {
+            model_type: ModelType::MultiLinearRegression,
+            model: registry::load("LinearRegression", loaded.model.params()).unwrap().1,
+            report: loaded.report.clone(),
+        };
+        let artifact = multi.to_artifact("run_1", "multi", "y").unwrap();
+        assert_eq!(artifact.model_type, "LinearRegression");
+        assert_eq!(SynapseModel::from_artifact(artifact).unwrap().model_type, ModelType::LinearRegression);
+
+        let rows = [1.0, 1.0, 1.0, 0.0, 2.0, 0.0];
+        let mut out = [0.0; 2];
+        let status = unsafe { synapse_model_predict(handle, rows.as_ptr(), 2, 3, out.as_mut_ptr()) };
+        assert_eq!(status, StatusCode::Ok);
+        assert!((out[0] - 43.1).abs() < 1e-12 && (out[1] - 41.2).abs() < 1e-12, "got {:?}", out);
+        unsafe { synapse_model_free(handle) };
+
+        // Saving and loading again gives back the same artifact
+        let artifact = ModelArtifact::load(path).unwrap();
+        let reloaded = ModelArtifact::from_json(&artifact.to_json().unwrap()).unwrap();
+        assert_eq!(reloaded.model_name, "Iris Flowers");
+        assert_eq!(reloaded.weights.data, artifact.weights.data);
+        assert_eq!(reloaded.to_json().unwrap(), artifact.to_json().unwrap());
+
+        let json = std::fs::read_to_string(path).unwrap();
+        let rejected = [
+            json.replace("\"0.1.0\"", "\"2.0.0\""),
+            json.replace("\"LinearRegression\"", "\"LogisticRegression\""),
+            json.replace("\"target\": \"flower_class\",", ""),
+            json.replace("\"bias\": 32", "\"bias\": 32, \"bias\": 1"),
+            json.replace("\"epochs_trained\": 10", "\"epochs_trained\": 1.5"),
+            json.replace("[6, 4.6, 0.5]", "[6, 4.6, 0.5"),
+        ];
+        for text in rejected {
+            let err = ModelArtifact::from_json(&text).and_then(SynapseModel::from_artifact);
+            assert!(matches!(err, Err(Error::InvalidInput(_))), "accepted {}", text);
+        }
+
+        // Deep nesting fails cleanly instead of overflowing the stack
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(json::parse(&nested(json::MAX_DEPTH)).is_ok());
+        assert!(matches!(json::parse(&nested(json::MAX_DEPTH + 1)), Err(Error::InvalidInput(_))));
+        assert!(matches!(ModelArtifact::from_json(&nested(1_000_000)), Err(Error::InvalidInput(_))));
+
+        // Numbers follow the JSON grammar, not the looser f64 parsing
+        for number in ["0", "-0.5", "10", "1e5", "2.5E-3", "-0e+0"] {
+            assert!(json::parse(number).is_ok(), "rejected {}", number);
+        }
+        for number in ["01", "00.5", "1.", "-.5", ".5", "1.e5", "1e", "+1", "--1", "1e+-2"] {
+            assert!(matches!(json::parse(number), Err(Error::InvalidInput(_))), "accepted {}", number);
+        }
+
+        let missing = CString::new("/nonexistent/model.json").unwrap();
+        let status = unsafe { synapse_model_load_json(missing.as_ptr(), &mut handle) };
+        assert_eq!(status, StatusCode::Io);
+    }
+
+    #[test]
+    fn training_report_records_run() {
+        use crate::ffi::{synapse_model_loss_history, synapse_model_training_report, train};
+        use crate::ffi::{MathInput, StatusCode, SynapseTrainingReport};
+        use crate::solver::StopReason;
+
+        let line = Line::<f64>::new();
+        let flipped: Vec<f64> = line.y.iter().map(|v| -v).collect();
+        let input = MathInput { epochs: 30, early_stop: 30, ..line.input() };
+        let run = |input: &MathInput, epochs: u32| {
+            let internal = unsafe { input.to_internal_checked::<f64>() }.unwrap();
+            train(internal, |p| p.epoch < epochs).unwrap().report
+        };
+
+        let report = run(&input, u32::MAX);
+        assert_eq!(report.stop_reason, StopReason::Finished);
+        assert_eq!((report.epochs_trained, report.train_loss.len(), report.val_loss.len()), (30, 30, 30));
+        assert_eq!(report.best_epoch, 30);
+        assert_eq!(report.best_val_loss, report.val_loss[29]);
+        assert!(report.val_loss[29] < report.val_loss[0]);
+
+        assert_eq!(run(&input, 5).stop_reason, StopReason::Cancelled);
+
+        // Fitting y moves away from -y, the first epoch stays the best
+        let report = run(&MathInput { early_stop: 3, test_target: flipped.as_ptr(), ..input }, u32::MAX);
+        assert_eq!(report.stop_reason, StopReason::EarlyStop);
+        assert_eq!((report.best_epoch, report.epochs_trained), (1, 4));
+
+        let report = run(&MathInput { epochs: 2_000, early_stop: 2_000, learning_rate: 50.0, ..input }, u32::MAX);
+        assert_eq!(report.stop_reason, StopReason::Diverged);
+        assert!(!report.val_loss.last().unwrap().is_finite());
+
+        let internal = unsafe { input.to_internal_checked::<f64>() }.unwrap();
+        let handle = Box::into_raw(Box::new(train(internal, |_| true).unwrap()));
+        let mut summary = SynapseTrainingReport::default();
+        let status = unsafe { synapse_model_training_report(handle, &mut summary) };
+        assert_eq!(status, StatusCode::Ok);
+        assert_eq!((summary.epochs_trained, summary.history_len), (30, 30));
+        assert_eq!(summary.stop_reason, StopReason::Finished);
+
+        let mut train_loss = [0.0; 30];
+        let status = unsafe { synapse_model_loss_history(handle, train_loss.as_mut_ptr(), core::ptr::null_mut(), 30) };
+        assert_eq!(status, StatusCode::Ok);
+        assert_eq!(train_loss.to_vec(), unsafe { &*handle }.report.train_loss);
+
+        let status = unsafe { synapse_model_loss_history(handle, train_loss.as_mut_ptr(), core::ptr::null_mut(), 31) };
+        assert_eq!(status, StatusCode::IndexOutOfBounds);
+        unsafe { crate::ffi::synapse_model_free(handle) };
+    }
+
+    #[test]
+    fn vector_matrix_operators() {
+        let a: Vector = Vector { data: vec![1.0, 2.0, 3.0] };
+        let b = Vector { data: vec![0.5, -1.0, 2.0] };
+
+        assert_eq!((&a + &b).data, vec![1.5, 1.0, 5.0]);
+        assert_eq!((&a - &b).data, a.try_sub(&b).unwrap().data);
+        assert_eq!((&a * 2.0).data, vec![2.0, 4.0, 6.0]);
+        assert_eq!((0.5 * &a).data, (&a / 2.0).data);
+        assert_eq!((-&b).data, vec![-0.5, 1.0, -2.0]);
+
+        let mut c = a.clone() + b.clone();
+        c -= &b;
+        c *= 3.0;
+        c += a.clone();
+        c[0] = 10.0;
+        assert_eq!(c.data, vec![10.0, 8.0, 12.0]);
+        assert_eq!(c[2], 12.0);
+
+        let m = Matrix::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let mut n = &m * 2.0 - &Matrix::identity(2);
+        assert_eq!(n.data(), vec![1.0, 4.0, 6.0, 7.0]);
+        n[(1, 0)] = 0.0;
+        n /= 2.0;
+        assert_eq!((n[(0, 1)], n[(1, 0)], n[(1, 1)]), (2.0, 0.0, 3.5));
+        assert_eq!((-(m.clone()) + m.clone()).data(), vec![0.0; 4]);
+
+        // Operators panic on shape mismatches, try_ forms return errors
+        let short = Vector::ones(2);
+        assert!(matches!(a.try_add(&short), Err(Error::VectorDimensionMismatch { .. })));
+        let panic = std::panic::catch_unwind(|| &a + &short).unwrap_err();
+        let msg = panic.downcast_ref::<String>().unwrap();
+        assert!(msg.contains("vector dimension mismatch in `+`: 3 vs 2"), "{}", msg);
+
+        let wide = Matrix::ones(2, 3);
+        assert!(matches!(m.try_sub(&wide), Err(Error::MatDimensionMismatch { .. })));
+        assert!(std::panic::catch_unwind(|| &m - &wide).is_err());
+        assert!(std::panic::catch_unwind(|| m[(0, 2)]).is_err());
+    }
+
+    #[test]
+    fn simd_kernels_match_reference() {
+        use crate::math::kernels::{self, portable};
+
+        let mut rng = Random::new(Some(14));
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-9 * (1.0 + b.abs());
+
+        // Lengths around the register width and unrolled loop boundaries
+        for len in [0, 1, 3, 4, 5, 15, 16, 17, 33, 1000] {
+            let a = rng.normal_vector(len, 0.0, 1.0).unwrap();
+            let b = rng.normal_vector(len, 0.0, 1.0).unwrap();
+            let expected = portable::dot(&a.data, &b.data);
+            assert!(close(kernels::dot(&a.data, &b.data), expected), "dot len {}", len);
+            assert!(close(a.dot(&b).unwrap(), expected));
+
+            let mut y = b.clone();
+            let mut y_ref = b.clone();
+            kernels::axpy(-1.5, &a.data, &mut y.data);
+            portable::axpy(-1.5, &a.data, &mut y_ref.data);
+            assert!(y.data.iter().zip(&y_ref.data).all(|(&u, &v)| close(u, v)), "axpy len {}", len);
+        }
+
+        // Shapes crossing the gemm block sizes
+        let (m, k, n) = (67, 259, 515);
+        let a = Matrix::from_vec(m, k, rng.normal_vector(m * k, 0.0, 1.0).unwrap().data).unwrap();
+        let b = Matrix::from_vec(k, n, rng.normal_vector(k * n, 0.0, 1.0).unwrap().data).unwrap();
+        let prod = a.mat_mul(&b).unwrap();
+        for i in 0..m {
+            for j in 0..n {
+                let expected: f64 = (0..k).map(|p| a[(i, p)] * b[(p, j)]).sum();
+                assert!(close(prod[(i, j)], expected), "mat_mul ({}, {})", i, j);
+            }
+        }
+
+        let x = rng.normal_vector(k, 0.0, 1.0).unwrap();
+        let res = a.vec_mul(&x).unwrap();
+        for i in 0..m {
+            let expected: f64 = (0..k).map(|p| a[(i, p)] * x[p]).sum();
+            assert!(close(res[i], expected), "vec_mul row {}", i);
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma") {
+            assert_eq!(kernels::backend(), kernels::Backend::Avx2Fma);
+        }
+    }
+
+    #[test]
+    fn parallel_kernels_match_serial() {
+        use crate::ffi::MathInput;
+        use crate::math::kernels::{self, parallel};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut rng = Random::new(Some(15));
+        let (m, k, n) = (150, 90, 70);
+        let a = Matrix::from_vec(m, k, rng.normal_vector(m * k, 0.0, 1.0).unwrap().data).unwrap();
+        let b = Matrix::from_vec(k, n, rng.normal_vector(k * n, 0.0, 1.0).unwrap().data).unwrap();
+        let x = rng.normal_vector(k, 0.0, 1.0).unwrap();
+
+        let run = |threads| kernels::with_num_threads(threads, || {
+            (a.mat_mul(&b).unwrap(), a.vec_mul(&x).unwrap(), a.transpose())
+        });
+        let (serial_mm, serial_mv, serial_t) = run(1);
+        let (par_mm, par_mv, par_t) = run(4);
+        // Rows are computed by the same kernels either way
+        assert_eq!(par_mm.data(), serial_mm.data());
+        assert_eq!(par_mv.data, serial_mv.data);
+        assert_eq!(par_t.data(), serial_t.data());
+        assert_eq!(par_t[(5, 7)], a[(7, 5)]);
+        assert_eq!(kernels::with_num_threads(3, kernels::num_threads), 3);
+
+        // Large outputs are split into one chunk per thread, small ones stay serial
+        let chunks = |len: usize| {
+            let calls = AtomicUsize::new(0);
+            let mut out = vec![0.0; len];
+            kernels::with_num_threads(4, || parallel::for_row_chunks(&mut out, 1, 1024, |_, _| {
+                calls.fetch_add(1, Ordering::Relaxed);
+            }));
+            calls.into_inner()
+        };
+        assert_eq!(chunks(1000), 4);
+        assert_eq!(chunks(8), 1);
+
+        // Version 1 callers don't send num_threads
+        let v1 = MathInput {
+            version: 1,
+            struct_size: core::mem::offset_of!(MathInput, num_threads) as u32,
+            num_threads: 8,
+            ..MathInput::default()
+        };
+        assert_eq!(unsafe { MathInput::read(&v1) }.unwrap().num_threads, 0);
+    }
+
+    #[test]
+    fn lu_solve_inverse_det() {
+        let a = Matrix::from_vec(3, 3, vec![
+            0.0, 2.0, 1.0,
+            1.0, 1.0, 0.0,
+            3.0, 0.0, 1.0,
+        ]).unwrap();
+        let close = |x: f64, y: f64| (x - y).abs() < 1e-12;
+
+        // PA = LU, the zero leading entry forces a pivot
+        let lu = a.lu().unwrap();
+        let pa = lu.p().mat_mul(&a).unwrap();
+        let prod = lu.l().mat_mul(&lu.u()).unwrap();
+        assert!(pa.data().iter().zip(prod.data()).all(|(&x, &y)| close(x, y)));
+
+        assert!(close(a.det().unwrap(), -5.0));
+
+        let b = Vector { data: vec![5.0, 3.0, 6.0] };
+        let x = a.solve(&b).unwrap();
+        let ax = a.vec_mul(&x).unwrap();
+        assert!(ax.data.iter().zip(&b.data).all(|(&u, &v)| close(u, v)), "{:?}", x);
+
+        let inv = a.inverse().unwrap();
+        let eye = a.mat_mul(&inv).unwrap();
+        assert!(eye.data().iter().zip(Matrix::identity(3).data()).all(|(&u, &v)| close(u, v)));
+
+        // Singular and nearly singular input is reported, not turned into NaNs
+        let singular = Matrix::from_vec(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        assert_eq!(singular.det().unwrap(), 0.0);
+        assert!(matches!(singular.solve(&Vector::ones(2)), Err(Error::SingularMatrix)));
+        let nearly = Matrix::from_vec(2, 2, vec![1.0, 1.0, 1.0, 1.0 + 3e-16]).unwrap();
+        assert!(matches!(nearly.inverse(), Err(Error::SingularMatrix)));
+
+        assert!(matches!(Matrix::ones(2, 3).det(), Err(Error::MatDimensionMismatch { .. })));
+        assert!(matches!(a.solve(&Vector::ones(2)), Err(Error::MatDimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn qr_and_least_squares() {
+        use crate::math::decomp::lstsq;
+
+        let mut rng = Random::new(Some(17));
+        let close = |x: f64, y: f64, tol: f64| (x - y).abs() < tol;
+
+        // Tall design matrix with an intercept column
+        let m = 30;
+        let mut a = Matrix::ones(m, 3);
+        for i in 0..m {
+            a[(i, 1)] = i as f64 / 10.0;
+            a[(i, 2)] = rng.normal_scalar(0.0, 1.0).unwrap();
+        }
+
+        let qr = a.qr();
+        let (q, r) = (qr.q(), qr.r());
+        assert_eq!((q.shape(), r.shape()), ((m, 3), (3, 3)));
+        let qtq = q.transpose().mat_mul(&q).unwrap();
+        assert!(qtq.data().iter().zip(Matrix::identity(3).data()).all(|(&u, &v)| close(u, v, 1e-12)));
+        let qr_prod = q.mat_mul(&r).unwrap();
+        assert!(qr_prod.data().iter().zip(a.data()).all(|(&u, &v)| close(u, v, 1e-12)));
+        assert!((0..3).all(|i| (0..i).all(|j| r[(i, j)] == 0.0)));
+
+        // Matches the normal equations on a noisy target
+        let noise = rng.normal_vector(m, 0.0, 0.1).unwrap();
+        let b = a.vec_mul(&Vector { data: vec![1.0, -2.0, 0.5] }).unwrap() + noise;
+        let fit = lstsq(a.view(), b.view()).unwrap();
+        let at = a.transpose();
+        let normal = at.mat_mul(&a).unwrap().solve(&at.vec_mul(&b).unwrap()).unwrap();
+        assert_eq!(fit.rank, 3);
+        assert!(fit.x.data.iter().zip(&normal.data).all(|(&u, &v)| close(u, v, 1e-10)));
+        let resid = a.vec_mul(&fit.x).unwrap() - &b;
+        assert!(close(fit.residual, resid.dot(&resid).unwrap(), 1e-10));
+
+        // A duplicated column leaves rank 3 of 4 and the same fitted values
+        let mut dup = Matrix::zeros(m, 4);
+        for i in 0..m {
+            for j in 0..3 {
+                dup[(i, j)] = a[(i, j)];
+            }
+            dup[(i, 3)] = a[(i, 1)];
+        }
+        let dup_fit = dup.lstsq(&b).unwrap();
+        assert_eq!(dup_fit.rank, 3);
+        assert!(close(dup_fit.residual, fit.residual, 1e-10));
+        assert!(close(dup_fit.x[1] + dup_fit.x[3], fit.x[1], 1e-10));
+
+        assert!(matches!(a.lstsq(&Vector::ones(m - 1)), Err(Error::MatDimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn cholesky_solves_spd_systems() {
+        use crate::math::decomp::{back_substitution, forward_substitution};
+
+        let close = |x: f64, y: f64| (x - y).abs() < 1e-10;
+
+        // Ridge normal equations XᵀX + λI are SPD
+        let mut rng = Random::new(Some(18));
+        let x = Matrix::from_vec(20, 4, rng.normal_vector(80, 0.0, 1.0).unwrap().data).unwrap();
+        let a = x.transpose().mat_mul(&x).unwrap() + Matrix::identity(4) * 0.1;
+
+        let chol = a.cholesky().unwrap();
+        let l = chol.l();
+        assert!((0..4).all(|i| (i + 1..4).all(|j| l[(i, j)] == 0.0)));
+        let llt = l.mat_mul(&l.transpose()).unwrap();
+        assert!(llt.data().iter().zip(a.data()).all(|(&u, &v)| close(u, v)));
+        assert!((chol.det() - a.det().unwrap()).abs() < 1e-8 * a.det().unwrap());
+
+        let b = Vector { data: vec![1.0, -1.0, 2.0, 0.5] };
+        let sol = chol.solve(&b).unwrap();
+        let lu_sol = a.solve(&b).unwrap();
+        assert!(sol.data.iter().zip(&lu_sol.data).all(|(&u, &v)| close(u, v)));
+
+        // Triangular solvers on their own
+        let y = forward_substitution(l, &b).unwrap();
+        assert!(l.vec_mul(&y).unwrap().data.iter().zip(&b.data).all(|(&u, &v)| close(u, v)));
+        let u = l.transpose();
+        let z = back_substitution(&u, &b).unwrap();
+        assert!(u.vec_mul(&z).unwrap().data.iter().zip(&b.data).all(|(&p, &q)| close(p, q)));
+        assert!(matches!(forward_substitution(&Matrix::zeros(2, 2), &Vector::ones(2)), Err(Error::SingularMatrix)));
+
+        // Indefinite, semi-definite and non symmetric input is rejected
+        let indefinite = Matrix::from_vec(2, 2, vec![1.0, 2.0, 2.0, 1.0]).unwrap();
+        assert!(matches!(indefinite.cholesky(), Err(Error::NotPositiveDefinite)));
+        let semi = Matrix::from_vec(2, 2, vec![1.0, 1.0, 1.0, 1.0]).unwrap();
+        assert!(matches!(semi.cholesky(), Err(Error::NotPositiveDefinite)));
+        let skewed = Matrix::from_vec(2, 2, vec![2.0, 1.0, 0.0, 2.0]).unwrap();
+        assert!(matches!(skewed.cholesky(), Err(Error::NotPositiveDefinite)));
+        assert!(matches!(Matrix::ones(2, 3).cholesky(), Err(Error::MatDimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn svd_pinv_rank_cond() {
+        let mut rng = Random::new(Some(19));
+        let close = |a: &Matrix, b: &Matrix| {
+            a.shape() == b.shape() && a.data().iter().zip(b.data()).all(|(&u, &v)| (u - v).abs() < 1e-10)
+        };
+        let rebuild = |svd: &crate::math::decomp::Svd| {
+            let mut us = svd.u.clone();
+            for i in 0..us.rows() {
+                for k in 0..us.cols() {
+                    us[(i, k)] *= svd.s[k];
+                }
+            }
+            us.mat_mul(&svd.vt).unwrap()
+        };
+
+        // Tall and wide matrices rebuild from orthonormal factors
+        for (m, n) in [(6, 4), (3, 5)] {
+            let a = Matrix::from_vec(m, n, rng.normal_vector(m * n, 0.0, 1.0).unwrap().data).unwrap();
+            let svd = a.svd().unwrap();
+            let k = m.min(n);
+            assert!(close(&svd.u.transpose().mat_mul(&svd.u).unwrap(), &Matrix::identity(k)));
+            assert!(close(&svd.vt.mat_mul(&svd.vt.transpose()).unwrap(), &Matrix::identity(k)));
+            assert!(svd.s.data.windows(2).all(|w| w[0] >= w[1]));
+            assert!(close(&rebuild(&svd), &a));
+            assert_eq!(a.rank().unwrap(), k);
+        }
+
+        // Square full rank: pinv is the inverse
+        let sq = Matrix::from_vec(3, 3, vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]).unwrap();
+        assert!(close(&sq.pinv().unwrap(), &sq.inverse().unwrap()));
+        assert!(sq.cond().unwrap() > 1.0 && sq.cond().unwrap() < 10.0);
+
+        // A duplicated feature column is collinear
+        let mut dup = Matrix::zeros(8, 3);
+        for i in 0..8 {
+            dup[(i, 0)] = 1.0;
+            dup[(i, 1)] = i as f64;
+            dup[(i, 2)] = i as f64;
+        }
+        assert_eq!(dup.rank().unwrap(), 2);
+        assert!(dup.cond().unwrap() > 1e12);
+        let pinv = dup.pinv().unwrap();
+        let back = dup.mat_mul(&pinv).unwrap().mat_mul(&dup).unwrap();
+        assert!(close(&back, &dup));
+        // Minimum norm solution splits the weight across the duplicates
+        assert!((pinv[(1, 3)] - pinv[(2, 3)]).abs() < 1e-12);
+
+        let nan = Matrix::from_vec(1, 1, vec![f64::NAN]).unwrap();
+        assert!(matches!(nan.svd(), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn symmetric_eigen_of_covariance() {
+        let close = |x: f64, y: f64| (x - y).abs() < 1e-10;
+
+        let simple = Matrix::from_vec(2, 2, vec![2.0, 1.0, 1.0, 2.0]).unwrap();
+        let eig = simple.symmetric_eigen().unwrap();
+        assert!(close(eig.values[0], 3.0) && close(eig.values[1], 1.0));
+
+        // Sample covariance of correlated features
+        let mut rng = Random::new(Some(20));
+        let (n, d) = (50, 4);
+        let mut x = Matrix::from_vec(n, d, rng.normal_vector(n * d, 0.0, 1.0).unwrap().data).unwrap();
+        for i in 0..n {
+            x[(i, 3)] = x[(i, 0)] + 0.1 * x[(i, 3)];
+        }
+        for j in 0..d {
+            let mean = (0..n).map(|i| x[(i, j)]).sum::<f64>() / n as f64;
+            for i in 0..n {
+                x[(i, j)] -= mean;
+            }
+        }
+        let cov = x.transpose().mat_mul(&x).unwrap() / (n - 1) as f64;
+
+        let eig = cov.symmetric_eigen().unwrap();
+        assert!(eig.values.data.windows(2).all(|w| w[0] >= w[1]));
+        let vtv = eig.vectors.transpose().mat_mul(&eig.vectors).unwrap();
+        assert!(vtv.data().iter().zip(Matrix::identity(d).data()).all(|(&u, &v)| close(u, v)));
+        for k in 0..d {
+            let v_k = Vector { data: (0..d).map(|i| eig.vectors[(i, k)]).collect() };
+            let av = cov.vec_mul(&v_k).unwrap();
+            assert!(av.data.iter().zip(&v_k.data).all(|(&u, &v)| close(u, eig.values[k] * v)));
+        }
+        // Trace is preserved and the near duplicate feature leaves a tiny eigenvalue
+        let trace: f64 = (0..d).map(|i| cov[(i, i)]).sum();
+        assert!(close(eig.values.sum(), trace));
+        assert!(eig.values[d - 1] < 0.02 * eig.values[0]);
+
+        // Wide covariance matrices converge too, rounding noise used to keep
+        // the off diagonal sum above the stopping threshold from about 40 features
+        for d in [40, 80] {
+            let n = 2 * d;
+            let x = Matrix::from_vec(n, d, rng.normal_vector(n * d, 0.0, 1.0).unwrap().data).unwrap();
+            let cov = x.transpose().mat_mul(&x).unwrap() / (n - 1) as f64;
+
+            let eig = cov.symmetric_eigen().unwrap();
+            let svd = cov.svd().unwrap();
+            let scale = eig.values[0];
+            assert!(eig.values.data.iter().zip(&svd.s.data).all(|(&l, &s)| (l - s).abs() < 1e-10 * scale));
+            let vtv = eig.vectors.transpose().mat_mul(&eig.vectors).unwrap();
+            assert!(vtv.data().iter().zip(Matrix::identity(d).data()).all(|(&u, &v)| close(u, v)));
+            let av = cov.mat_mul(&eig.vectors).unwrap();
+            assert!((0..d).all(|k| (0..d).all(|i| (av[(i, k)] - eig.values[k] * eig.vectors[(i, k)]).abs() < 1e-10 * scale)));
+        }
+
+        let skewed = Matrix::from_vec(2, 2, vec![1.0, 2.0, 0.0, 1.0]).unwrap();
+        assert!(matches!(skewed.symmetric_eigen(), Err(Error::InvalidInput(_))));
+        assert!(matches!(Matrix::ones(2, 3).symmetric_eigen(), Err(Error::MatDimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn slicing_and_stacking() {
+        use crate::error::MatrixIndex;
+
+        let m = Matrix::from_vec(3, 3, (1..=9).map(f64::from).collect()).unwrap();
+
+        assert_eq!(m.row_iter().collect::<Vec<_>>(), vec![&[1.0, 2.0, 3.0][..], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]]);
+        assert_eq!(m.column(1).unwrap().data, vec![2.0, 5.0, 8.0]);
+        assert!(m.column(3).is_none());
+
+        let tail = m.slice_rows(1..3).unwrap();
+        assert_eq!((tail.rows(), tail.data()), (2, &[4.0, 5.0, 6.0, 7.0, 8.0, 9.0][..]));
+        assert_eq!(m.submatrix(0..2, 1..3).unwrap().data(), vec![2.0, 3.0, 5.0, 6.0]);
+        let err = m.submatrix(0..4, 0..1).unwrap_err();
+        assert!(matches!(err, Error::MatrixIndexOutOfBounds { index: MatrixIndex::Row(3), shape: (3, 3) }));
+        assert_eq!(err.to_string(), "matrix row 3 out of bounds for 3x3 matrix");
+        let (start, end) = (2, 1);
+        assert!(matches!(m.submatrix(0..1, start..end), Err(Error::InvalidParameter(_))));
+
+        assert_eq!(m.select_rows(&[2, 0, 2]).unwrap().data(), vec![7.0, 8.0, 9.0, 1.0, 2.0, 3.0, 7.0, 8.0, 9.0]);
+        assert_eq!(m.select_cols(&[2, 0]).unwrap().data(), vec![3.0, 1.0, 6.0, 4.0, 9.0, 7.0]);
+        assert!(matches!(m.select_cols(&[0, 3]), Err(Error::MatrixIndexOutOfBounds { index: MatrixIndex::Column(3), .. })));
+        let mut e = m.clone();
+        assert_eq!(e.set(1, 3, 0.0).unwrap_err().to_string(), "matrix index (1, 3) out of bounds for 3x3 matrix");
+        let v = Vector { data: vec![10.0, 20.0, 30.0] };
+        assert_eq!(v.select(&[1, 1]).unwrap().data, vec![20.0, 20.0]);
+        assert!(matches!(v.select(&[3]), Err(Error::IndexOutOfBounds { .. })));
+
+        let h = Matrix::hstack(&[m.view(), Matrix::ones(3, 1).view()]).unwrap();
+        assert_eq!((h.rows(), h.cols()), (3, 4));
+        assert_eq!(h.row(1).unwrap(), &[4.0, 5.0, 6.0, 1.0]);
+        let s = Matrix::vstack(&[m.view(), tail]).unwrap();
+        assert_eq!((s.rows(), s.cols()), (5, 3));
+        assert!(matches!(Matrix::vstack(&[m.view(), Matrix::ones(1, 2).view()]), Err(Error::MatDimensionMismatch { .. })));
+
+        let mut g = m.clone();
+        assert_eq!(g.remove_col(0).unwrap().data, vec![1.0, 4.0, 7.0]);
+        assert_eq!((g.cols(), g.data()), (2, &[2.0, 3.0, 5.0, 6.0, 8.0, 9.0][..]));
+        g.push_col(&v).unwrap();
+        g.push_row(&Vector { data: vec![0.0, 0.0, 0.0] }).unwrap();
+        assert_eq!((g.rows(), g.cols()), (4, 3));
+        assert_eq!(g.column(2).unwrap().data, vec![10.0, 20.0, 30.0, 0.0]);
+        assert!(matches!(g.push_row(&v.select(&[0]).unwrap()), Err(Error::MatDimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn axis_reductions_and_broadcasting() {
+        use crate::math::Axis;
+
+        let m: Matrix = Matrix::from_vec(2, 3, vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0]).unwrap();
+        assert_eq!(m.sum_axis(Axis::Row).data, vec![9.0, 12.0]);
+        assert_eq!(m.sum_axis(Axis::Column).data, vec![5.0, 7.0, 9.0]);
+        assert_eq!(m.mean_axis(Axis::Column).unwrap().data, vec![2.5, 3.5, 4.5]);
+        assert_eq!(m.min_axis(Axis::Row).unwrap().data, vec![1.0, 2.0]);
+        assert_eq!(m.max_axis(Axis::Column).unwrap().data, vec![4.0, 5.0, 6.0]);
+        assert_eq!(m.argmax_axis(Axis::Row).unwrap(), vec![1, 2]);
+        assert_eq!(m.argmin_axis(Axis::Column).unwrap(), vec![0, 1, 0]);
+        assert!(matches!(Matrix::<f64>::zeros(2, 0).mean_axis(Axis::Row), Err(Error::InsufficientData)));
+
+        // Column centering, then a row wise softmax
+        let centered = m.broadcast_sub(&m.mean_axis(Axis::Column).unwrap(), Axis::Column).unwrap();
+        assert!(centered.sum_axis(Axis::Column).data.iter().all(|&s| s.abs() < 1e-12));
+        let shifted = m.broadcast_sub(&m.max_axis(Axis::Row).unwrap(), Axis::Row).unwrap();
+        let exp = Matrix::from_vec(2, 3, shifted.data().iter().map(|v| v.exp()).collect()).unwrap();
+        let softmax = exp.broadcast_div(&exp.sum_axis(Axis::Row), Axis::Row).unwrap();
+        assert!(softmax.sum_axis(Axis::Row).data.iter().all(|&s| (s - 1.0).abs() < 1e-12));
+        assert_eq!(softmax.argmax_axis(Axis::Row).unwrap(), m.argmax_axis(Axis::Row).unwrap());
+
+        let scaled = m.broadcast_mul(&Vector { data: vec![1.0, 0.0, -1.0] }, Axis::Column).unwrap();
+        assert_eq!(scaled.data(), vec![1.0, 0.0, -3.0, 4.0, 0.0, -6.0]);
+        assert_eq!(m.broadcast_add(&Vector { data: vec![10.0, 20.0] }, Axis::Row).unwrap().data(),
+            vec![11.0, 15.0, 13.0, 24.0, 22.0, 26.0]);
+        assert!(matches!(m.broadcast_add(&Vector::ones(3), Axis::Row), Err(Error::MatDimensionMismatch { .. })));
+        assert_eq!((Vector { data: vec![1.0, 2.0] } + 0.5).data, vec![1.5, 2.5]);
+    }
+
+    #[test]
+    fn element_wise_map_and_vectorized_functions() {
+        use crate::linear_algebra::activation::{Activation, Sigmoid};
+
+        let mut v = Vector { data: vec![0.0, 1.0, 4.0] };
+        assert_eq!(v.map(|x| 2.0 * x + 1.0).data, vec![1.0, 3.0, 9.0]);
+        assert_eq!(v.sqrt().data, vec![0.0, 1.0, 2.0]);
+        assert_eq!(v.exp().ln().data, v.data);
+        assert_eq!(v.zip_map(&Vector::ones(3), |a, b| a - b).unwrap().data, vec![-1.0, 0.0, 3.0]);
+        assert!(matches!(v.zip_map(&Vector::ones(2), |a, _| a), Err(Error::VectorDimensionMismatch { .. })));
+        v.map_inplace(|x| -x);
+        assert_eq!(v.abs().data, vec![0.0, 1.0, 4.0]);
+
+        let mut m: Matrix = Matrix::from_vec(2, 2, vec![0.0, 1.0, -1.0, 2.0]).unwrap();
+        let t = m.tanh();
+        assert_eq!(t.shape(), (2, 2));
+        assert!(t.data().iter().zip(m.data()).all(|(&a, &b)| a == b.tanh()));
+        assert!(m.sin().zip_map(&m.cos(), |s, c| s * s + c * c).unwrap().data().iter().all(|&x| (x - 1.0).abs() < 1e-15));
+        assert!(matches!(m.zip_map(&Matrix::ones(1, 2), |a, _| a), Err(Error::MatDimensionMismatch { .. })));
+        m.map_inplace(|x| x * x);
+        assert_eq!(m.data(), vec![0.0, 1.0, 1.0, 4.0]);
+
+        let s = Sigmoid::forward_mat(&m);
+        assert_eq!(s.data()[0], 0.5);
+        assert_eq!(Sigmoid::backward_vec(&Vector { data: vec![0.0] }).data, vec![0.25]);
+    }
+
+    #[test]
+    fn f32_math_and_ffi_input() {
+        use crate::ffi::{synapse_train, synapse_model_free, ElementType, MathInput, StatusCode, SynapseModel};
+        use crate::linear_algebra::activation::{Activation, Sigmoid};
+        use crate::linear_algebra::gradient::{OptimizerConfig, OptimizerKind};
+        use crate::linear_algebra::loss::{Loss, MSE};
+        use crate::models::linear_regression::LinearRegression;
+
+        // Single precision runs through the same containers and kernels
+        let a = Matrix::<f32>::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let at_a = a.transpose().mat_mul(&a).unwrap();
+        assert_eq!(at_a.row(0).unwrap(), &[17.0f32, 22.0, 27.0]);
+        let v = Vector::<f32> { data: vec![1.0, 0.0, -1.0] };
+        assert_eq!(a.vec_mul(&v).unwrap().data, vec![-2.0f32, -2.0]);
+        assert_eq!((2.0f32 * &v).dot(&v).unwrap(), 4.0);
+        assert_eq!(Sigmoid::forward_vec(&Vector::<f32>::zeroes(2)).data, vec![0.5f32; 2]);
+        assert_eq!(MSE::loss(&v, &Vector::zeroes(3)).unwrap(), 2.0f32 / 3.0);
+
+        let mut adam = OptimizerConfig { kind: OptimizerKind::Adam, ..OptimizerConfig::default() }.build(0.1f32, 1);
+        let mut p = Vector::<f32> { data: vec![2.0] };
+        for _ in 0..20 {
+            let grad = &p * 2.0;
+            adam.update(&mut p, &grad).unwrap();
+        }
+        assert!(p[0].abs() < 0.5);
+
+        // The line fed to the engine as f32 is trained in f32
+        let line = Line::<f32>::new();
+        let input = line.input();
+        assert!(matches!(unsafe { input.to_internal_checked::<f64>() }, Err(Error::InvalidInput(_))));
+        let internal = unsafe { input.to_internal_checked::<f32>() }.unwrap();
+        assert!(core::ptr::eq(internal.train_x.data().as_ptr(), line.x.as_ptr()));
+
+        let mut handle = core::ptr::null_mut();
+        let status = unsafe { synapse_train(&input, None, core::ptr::null_mut(), &mut handle) };
+        assert_eq!(status, StatusCode::Ok);
+        let trained: &SynapseModel = unsafe { &*handle };
+        let model = trained.model.as_any().downcast_ref::<LinearRegression<f32>>().unwrap();
+        assert_fits_line(model.weights[0].into(), model.bias.into());
+        let preds = trained.predict(Matrix::from_vec(1, 1, vec![0.5]).unwrap().view()).unwrap();
+        assert!(preds[0].abs() < 1e-3);
+        unsafe { synapse_model_free(handle) };
+
+        let missing = MathInput { test_target_f32: core::ptr::null(), ..input };
+        let mut handle = core::ptr::null_mut();
+        let status = unsafe { synapse_train(&missing, None, core::ptr::null_mut(), &mut handle) };
+        assert_eq!(status, StatusCode::NullPointer);
+        assert!(handle.is_null());
+
+        // Version 2 callers keep sending f64 data, whatever the tail padding
+        // of their struct (184 bytes on 64 bit targets) holds where version 3
+        // put element_type
+        let v2_end = core::mem::offset_of!(MathInput, num_threads) + core::mem::size_of::<u32>();
+        let v2 = MathInput {
+            version: 2,
+            struct_size: v2_end.next_multiple_of(core::mem::align_of::<MathInput>()) as u32,
+            ..input
+        };
+        assert_eq!(unsafe { MathInput::read(&v2) }.unwrap().element_type, ElementType::F64 as u32);
+    }
+
+    #[test]
+    fn checked_matrix_constructors() {
+        use crate::math::MatrixView;
+
+        let m: Matrix = Matrix::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!((m.rows(), m.cols(), m.shape()), (2, 3, (2, 3)));
+        assert_eq!(m.get(1, 2), Some(6.0));
+        assert!(matches!(Matrix::from_vec(2, 2, vec![1.0; 3]), Err(Error::MatDimensionMismatch { .. })));
+
+        let r: Matrix = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!((r.shape(), r.data()), (m.shape(), m.data()));
+        let ragged: Vec<Vec<f64>> = vec![vec![1.0, 2.0], vec![3.0]];
+        assert!(matches!(Matrix::from_rows(&ragged), Err(Error::MatDimensionMismatch { .. })));
+
+        let f: Matrix = Matrix::from_fn(2, 3, |i, j| (i * 3 + j + 1) as f64).unwrap();
+        assert_eq!((f.shape(), f.data()), (m.shape(), m.data()));
+        assert!(matches!(Matrix::<f64>::from_fn(usize::MAX, 2, |_, _| 0.0), Err(Error::InvalidInput(_))));
+
+        let d: Matrix = Matrix::diag(&Vector { data: vec![2.0, 3.0] }).unwrap();
+        assert_eq!(d.data(), &[2.0, 0.0, 0.0, 3.0][..]);
+
+        let mut e = m.clone();
+        e.data_mut()[0] = 10.0;
+        assert_eq!(e.into_vec(), vec![10.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        // Views are checked the same way so the matrices copied out of them are too
+        assert!(matches!(MatrixView::new(3, 3, &[1.0, 2.0]), Err(Error::MatDimensionMismatch { .. })));
+        assert!(matches!(MatrixView::<f64>::new(usize::MAX, 2, &[]), Err(Error::MatDimensionMismatch { .. })));
+        let v = MatrixView::new(2, 3, m.data()).unwrap();
+        let stacked = Matrix::hstack(&[v, v.to_matrix().view()]).unwrap();
+        assert_eq!((stacked.shape(), stacked.row(1).unwrap()), ((2, 6), &[4.0, 5.0, 6.0, 4.0, 5.0, 6.0][..]));
+    }
 }
@@ -5,24 +5,44 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 20 May 2025
-/// Last Modified: 20 May 2025
+/// Last Modified: 17 October 2026
 
-use crate::math::{self, exp, scalar::neg, Scalar};
+use crate::math::{self, exp, Float, Matrix, Vector};
 
 pub trait Activation {
-  fn forward(x: Scalar) -> Scalar;
-  fn backward(x: Scalar) -> Scalar;
+  fn forward<T: Float>(x: T) -> T;
+  fn backward<T: Float>(x: T) -> T;
+
+  /// `forward` over every entry of a Vector
+  fn forward_vec<T: Float>(x: &Vector<T>) -> Vector<T> {
+    x.map(Self::forward)
+  }
+
+  /// `backward` over every entry of a Vector
+  fn backward_vec<T: Float>(x: &Vector<T>) -> Vector<T> {
+    x.map(Self::backward)
+  }
+
+  /// `forward` over every entry of a batch
+  fn forward_mat<T: Float>(x: &Matrix<T>) -> Matrix<T> {
+    x.map(Self::forward)
+  }
+
+  /// `backward` over every entry of a batch
+  fn backward_mat<T: Float>(x: &Matrix<T>) -> Matrix<T> {
+    x.map(Self::backward)
+  }
 }
 
 pub struct ReLU;
 impl Activation for ReLU {
   #[inline]
-  fn forward(x: Scalar) -> Scalar {
+  fn forward<T: Float>(x: T) -> T {
     relu(x)
   }
 
   #[inline]
-  fn backward(x: Scalar) -> Scalar {
+  fn backward<T: Float>(x: T) -> T {
     relu_grad(x)
   }
 }
@@ -30,12 +50,12 @@ impl Activation for ReLU {
 pub struct Sigmoid;
 impl Activation for Sigmoid {
   #[inline]
-  fn forward(x: Scalar) -> Scalar {
+  fn forward<T: Float>(x: T) -> T {
     sigmoid(x)
   }
 
   #[inline]
-  fn backward(x: Scalar) -> Scalar {
+  fn backward<T: Float>(x: T) -> T {
     sigmoid_grad(x)
   }
 }
@@ -43,33 +63,32 @@ impl Activation for Sigmoid {
 pub struct Tanh;
 impl Activation for Tanh {
   #[inline]
-  fn forward(x: Scalar) -> Scalar {
+  fn forward<T: Float>(x: T) -> T {
     tanh(x)
   }
 
   #[inline]
-  fn backward(x: Scalar) -> Scalar {
+  fn backward<T: Float>(x: T) -> T {
     tanh_grad(x)
   }
 }
 
 #[inline]
-fn relu(x: Scalar) -> Scalar { x.max(0.0) }
+fn relu<T: Float>(x: T) -> T { x.max(T::ZERO) }
 
 #[inline]
-fn relu_grad(x: Scalar) -> Scalar { if x > 0.0 { 1.0 } else { 0.0 } }
+fn relu_grad<T: Float>(x: T) -> T { if x > T::ZERO { T::ONE } else { T::ZERO } }
 
 #[inline]
-fn sigmoid(x: Scalar) -> Scalar { 1.0 / (1.0 + (exp(neg(x)))) }
+fn sigmoid<T: Float>(x: T) -> T { T::ONE / (T::ONE + exp(-x)) }
 
 #[inline]
-fn sigmoid_grad(x: Scalar) -> Scalar { sigmoid(x) * (1.0 - sigmoid(x)) }
+fn sigmoid_grad<T: Float>(x: T) -> T { sigmoid(x) * (T::ONE - sigmoid(x)) }
 
 #[inline]
-fn tanh(x: Scalar) -> Scalar { math::tanh(x) }
+fn tanh<T: Float>(x: T) -> T { math::tanh(x) }
 
 #[inline]
-fn tanh_grad(x: Scalar) -> Scalar { 1.0 - math::tanh(x).powi(2) }
+fn tanh_grad<T: Float>(x: T) -> T { T::ONE - math::tanh(x).powi(2) }
 
 // TODO: Implement softmax and softmax_jacobian
-// TODO: Add Vector Activation functions
@@ -5,14 +5,15 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 20 May 2025
-/// Last Modified: 20 May 2025
+/// Last Modified: 17 October 2026
 
-use crate::math::{Scalar, Vector};
+use crate::math::{Float, Scalar, Vector};
 use crate::error::Error;
 
-pub fn numeric_grad<F>(f: F, params: &Vector, eps: Scalar) -> Vector
+pub fn numeric_grad<T, F>(f: F, params: &Vector<T>, eps: T) -> Vector<T>
 where
-  F: Fn(&Vector) -> Scalar,
+  T: Float,
+  F: Fn(&Vector<T>) -> T,
 {
   let n = params.len();
   let mut grad = Vector::zeroes(n);
@@ -25,7 +26,7 @@ where
 
     let f_plus = f(&theta_plus);
     let f_minus = f(&theta_minus);
-    let derivative = (f_plus - f_minus) / (2.0 * eps);
+    let derivative = (f_plus - f_minus) / (eps + eps);
 
     grad.set(idx, derivative).unwrap();
   }
@@ -33,76 +34,154 @@ where
   grad
 }
 
-pub trait Optimizer {
-  fn update(&mut self, params: &mut Vector, grad: &Vector) -> Result<(), Error>;
+/// Parameter update rule, generic over the Float of the parameters
+pub trait Optimizer<T: Float = Scalar> {
+  fn update(&mut self, params: &mut Vector<T>, grad: &Vector<T>) -> Result<(), Error>;
 }
 
-pub struct SGD {
-  pub lr: Scalar,
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OptimizerKind {
+  #[default]
+  SGD = 0,
+  Momentum = 1,
+  Adam = 2,
 }
 
-impl Optimizer for SGD {
-  fn update(&mut self, params: &mut Vector, grad: &Vector) -> Result<(), Error> {
+/// Decode the `optimizer` field of a MathInput
+impl TryFrom<u32> for OptimizerKind {
+  type Error = Error;
+
+  fn try_from(value: u32) -> Result<Self, Error> {
+    match value {
+      0 => Ok(OptimizerKind::SGD),
+      1 => Ok(OptimizerKind::Momentum),
+      2 => Ok(OptimizerKind::Adam),
+      _ => Err(Error::InvalidInput(format!("unknown optimizer kind {}", value))),
+    }
+  }
+}
+
+/// Hyperparameters needed to build any of the optimizers,
+/// fields not used by `kind` are ignored
+#[derive(Debug, Clone, Copy)]
+pub struct OptimizerConfig {
+  pub kind: OptimizerKind,
+  pub momentum: Scalar,
+  pub beta1: Scalar,
+  pub beta2: Scalar,
+  pub eps: Scalar,
+}
+
+impl Default for OptimizerConfig {
+  fn default() -> Self {
+    OptimizerConfig {
+      kind: OptimizerKind::SGD,
+      momentum: 0.9,
+      beta1: 0.9,
+      beta2: 0.999,
+      eps: 1e-8,
+    }
+  }
+}
+
+impl OptimizerConfig {
+  /// Build the optimizer with learning rate `lr` for `n_params` parameters,
+  /// the hyperparameters are converted to the Float of `lr`
+  pub fn build<T: Float>(&self, lr: T, n_params: usize) -> Box<dyn Optimizer<T>> {
+    match self.kind {
+      OptimizerKind::SGD => Box::new(SGD { lr }),
+      OptimizerKind::Momentum => Box::new(Momentum {
+        lr,
+        momentum: T::from_f64(self.momentum),
+        velocity: Vector::zeroes(n_params),
+      }),
+      OptimizerKind::Adam => Box::new(Adam {
+        lr,
+        beta1: T::from_f64(self.beta1),
+        beta2: T::from_f64(self.beta2),
+        eps: T::from_f64(self.eps),
+        m: Vector::zeroes(n_params),
+        v: Vector::zeroes(n_params),
+        t: 0,
+      }),
+    }
+  }
+}
+
+/// Elastic net penalty `l1 * SUM|w| + l2 * SUM(w^2)` on model weights
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Regularization {
+  pub l1: Scalar,
+  pub l2: Scalar,
+}
+
+impl Regularization {
+  /// Gradient of the penalty for a single weight
+  #[inline]
+  pub fn grad<T: Float>(&self, w: T) -> T {
+    let sign = if w > T::ZERO { T::ONE } else if w < T::ZERO { -T::ONE } else { T::ZERO };
+    T::from_f64(self.l1) * sign + T::from_f64(2.0 * self.l2) * w
+  }
+}
+
+pub struct SGD<T = Scalar> {
+  pub lr: T,
+}
+
+impl<T: Float> Optimizer<T> for SGD<T> {
+  fn update(&mut self, params: &mut Vector<T>, grad: &Vector<T>) -> Result<(), Error> {
     let n = params.len();
     if n != grad.len() {
-      return Err(Error::VectorDimensionMismatch);
+      return Err(Error::VectorDimensionMismatch { left: n, right: grad.len() });
     }
 
-    for idx in 0..n {
-      let p = params.get(idx).unwrap();
-      let g = grad.get(idx).unwrap();
-      params.set(idx, p - self.lr * g)?;
-    } 
+    *params -= grad * self.lr;
 
     Ok(())
   }
 }
 
-pub struct Momentum {
-  pub lr: Scalar,
-  pub momentum: Scalar,
-  pub velocity: Vector,
+pub struct Momentum<T = Scalar> {
+  pub lr: T,
+  pub momentum: T,
+  pub velocity: Vector<T>,
 }
 
-impl Optimizer for Momentum {
-  fn update(&mut self, params: &mut Vector, grad: &Vector) -> Result<(), Error> {
+impl<T: Float> Optimizer<T> for Momentum<T> {
+  fn update(&mut self, params: &mut Vector<T>, grad: &Vector<T>) -> Result<(), Error> {
     let n = params.len();
-    if n != grad.len() || n != self.velocity.len() {
-      return Err(Error::MatrixIndexOutOfBounds);
+    if let Some(len) = [grad.len(), self.velocity.len()].into_iter().find(|&len| len != n) {
+      return Err(Error::VectorDimensionMismatch { left: n, right: len });
     }
 
-    for idx in 0..n {
-      let v_prev = self.velocity.get(idx).unwrap();
-      let g = grad.get(idx).unwrap();
-      let v_new = self.momentum * v_prev + self.lr * g;
-      self.velocity.set(idx, v_new)?;
-      let p = params.get(idx).unwrap();
-      params.set(idx, p - v_new)?;
-    }
+    self.velocity *= self.momentum;
+    self.velocity += grad * self.lr;
+    *params -= &self.velocity;
 
     Ok(())
   }
 }
 
-pub struct Adam {
-  pub lr: Scalar,
-  pub beta1: Scalar,
-  pub beta2: Scalar,
-  pub eps: Scalar,
-  pub m: Vector,
-  pub v: Vector,
+pub struct Adam<T = Scalar> {
+  pub lr: T,
+  pub beta1: T,
+  pub beta2: T,
+  pub eps: T,
+  pub m: Vector<T>,
+  pub v: Vector<T>,
   pub t: usize,
 }
 
-impl Optimizer for Adam {
-  fn update(&mut self, params: &mut Vector, grad: &Vector) -> Result<(), Error> {
+impl<T: Float> Optimizer<T> for Adam<T> {
+  fn update(&mut self, params: &mut Vector<T>, grad: &Vector<T>) -> Result<(), Error> {
     let n = params.len();
-    if n != grad.len() || n != self.m.len() || n != self.v.len() {
-      return Err(Error::VectorDimensionMismatch);
+    if let Some(len) = [grad.len(), self.m.len(), self.v.len()].into_iter().find(|&len| len != n) {
+      return Err(Error::VectorDimensionMismatch { left: n, right: len });
     }
 
     self.t += 1;
-    let t_f = self.t as Scalar;
+    let t_f = T::from_usize(self.t);
 
     for idx in 0..n {
       let g = grad.get(idx).unwrap();
@@ -110,14 +189,14 @@ impl Optimizer for Adam {
       let v_prev = self.v.get(idx).unwrap();
 
       // Update bias first & second moments
-      let m_new = self.beta1 * m_prev + (1.0 - self.beta1) * g;
-      let v_new = self.beta2 * v_prev + (1.0 - self.beta2) * (g * g);
+      let m_new = self.beta1 * m_prev + (T::ONE - self.beta1) * g;
+      let v_new = self.beta2 * v_prev + (T::ONE - self.beta2) * (g * g);
       self.m.set(idx, m_new)?;
       self.v.set(idx, v_new)?;
 
       // Compute bias-corrected moments
-      let m_hat = m_new / (1.0 - self.beta1.powf(t_f));
-      let v_hat = v_new / (1.0 - self.beta2.powf(t_f));
+      let m_hat = m_new / (T::ONE - self.beta1.powf(t_f));
+      let v_hat = v_new / (T::ONE - self.beta2.powf(t_f));
 
       // Update parameters
       let p = params.get(idx).unwrap();
@@ -5,130 +5,141 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 20 May 2025
-/// Last Modified: 20 May 2025
+/// Last Modified: 17 October 2026
 
-use crate::math::{ln, Scalar, Vector};
+use crate::math::{ln, Float, Vector, VectorView};
 use crate::error::Error;
 
+/// Loss functions accept both owned Vectors and borrowed VectorViews
+/// of any Float element type
 pub trait Loss {
-  fn loss(pred: &Vector, target: &Vector) -> Result<Scalar, Error>;
-  fn grad(pred: &Vector, target: &Vector) -> Result<Vector, Error>;
+  fn loss<'p, 't, T: Float>(pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<T, Error>;
+  fn grad<'p, 't, T: Float>(pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<Vector<T>, Error>;
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LossKind {
+  #[default]
+  MSE = 0,
+  CrossEntropy = 1,
+}
+
+/// Decode the `loss` field of a MathInput
+impl TryFrom<u32> for LossKind {
+  type Error = Error;
+
+  fn try_from(value: u32) -> Result<Self, Error> {
+    match value {
+      0 => Ok(LossKind::MSE),
+      1 => Ok(LossKind::CrossEntropy),
+      _ => Err(Error::InvalidInput(format!("unknown loss kind {}", value))),
+    }
+  }
+}
+
+impl LossKind {
+  /// Loss of the selected kind, see `Loss::loss`
+  pub fn loss<'p, 't, T: Float>(&self, pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<T, Error> {
+    match self {
+      LossKind::MSE => MSE::loss(pred, target),
+      LossKind::CrossEntropy => CrossEntropy::loss(pred, target),
+    }
+  }
+
+  /// Gradient of the selected kind, see `Loss::grad`
+  pub fn grad<'p, 't, T: Float>(&self, pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<Vector<T>, Error> {
+    match self {
+      LossKind::MSE => MSE::grad(pred, target),
+      LossKind::CrossEntropy => CrossEntropy::grad(pred, target),
+    }
+  }
 }
 
 pub struct MSE;
 impl Loss for MSE {
-  fn loss(pred: &Vector, target: &Vector) -> Result<Scalar, Error> {
-      mse(pred, target)
+  fn loss<'p, 't, T: Float>(pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<T, Error> {
+      mse(pred.into(), target.into())
   }
   
-  fn grad(pred: &Vector, target: &Vector) -> Result<Vector, Error> {
-      mse_grad(pred, target)
+  fn grad<'p, 't, T: Float>(pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<Vector<T>, Error> {
+      mse_grad(pred.into(), target.into())
   }
 }
 
 pub struct CrossEntropy;
 impl Loss for CrossEntropy {
-  fn loss(pred: &Vector, target: &Vector) -> Result<Scalar, Error> {
-      cross_entropy(pred, target)
+  fn loss<'p, 't, T: Float>(pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<T, Error> {
+      cross_entropy(pred.into(), target.into())
   }
 
-  fn grad(pred: &Vector, target: &Vector) -> Result<Vector, Error> {
-      cross_entropy_grad(pred, target)
+  fn grad<'p, 't, T: Float>(pred: impl Into<VectorView<'p, T>>, target: impl Into<VectorView<'t, T>>) -> Result<Vector<T>, Error> {
+      cross_entropy_grad(pred.into(), target.into())
   }
 }
 
 /// Mean Squared Error: (1/n) * SUM(pred_i - target_i)^2
-fn mse(pred: &Vector, target: &Vector) -> Result<Scalar, Error> {
+fn mse<T: Float>(pred: VectorView<T>, target: VectorView<T>) -> Result<T, Error> {
   let n = pred.len();
-  if n != target.len() {
-    return Err(Error::VectorDimensionMismatch);
-  }
-
-  if n == 0 {
-    return Err(Error::VectorDimensionMismatch);
+  if n != target.len() || n == 0 {
+    return Err(Error::VectorDimensionMismatch { left: n, right: target.len() });
   }
 
   let sum_sq = pred.data.iter()
-    .zip(&target.data)
+    .zip(target.data)
     .map(|(&p, &t)| {
       let d = p - t;
       d * d
     })
-    .sum::<Scalar>();
+    .sum::<T>();
 
-  Ok(sum_sq / (n as Scalar))
+  Ok(sum_sq / T::from_usize(n))
 }
 
 /// Gradient of MSE. predictions: (2/n)*(pred - target)
-fn mse_grad(pred: &Vector, target: &Vector) -> Result<Vector, Error> {
+fn mse_grad<T: Float>(pred: VectorView<T>, target: VectorView<T>) -> Result<Vector<T>, Error> {
   let n = pred.len();
-  if n != target.len() {
-    return Err(Error::VectorDimensionMismatch);
-  }
-
-  if n == 0 {
-    return Err(Error::VectorDimensionMismatch);
-  }
-
-  let factor = 2.0 / (n as Scalar);
-  let mut grad = Vector::zeroes(n);
-  for idx in 0..n {
-    let p = pred.get(idx).unwrap();
-    let t = target.get(idx).unwrap();
-    grad.set(idx, factor * (p - t))?;
+  if n != target.len() || n == 0 {
+    return Err(Error::VectorDimensionMismatch { left: n, right: target.len() });
   }
 
-  Ok(grad)
+  let factor = T::from_f64(2.0) / T::from_usize(n);
+  pred.zip_map(target, |p, t| factor * (p - t))
 }
 
 /// Cross Entropy Loss: -(1/n) * SUM([Ti * ln(Pi)])
-fn cross_entropy(pred: &Vector, target: &Vector) -> Result<Scalar, Error> {
+fn cross_entropy<T: Float>(pred: VectorView<T>, target: VectorView<T>) -> Result<T, Error> {
   let n = pred.len();
-  if n != target.len() {
-    return Err(Error::VectorDimensionMismatch);
-  }
-
-  if n == 0 {
-    return Err(Error::VectorDimensionMismatch);
+  if n != target.len() || n == 0 {
+    return Err(Error::VectorDimensionMismatch { left: n, right: target.len() });
   }
 
   let sum_ce = pred.data.iter()
-    .zip(&target.data)
+    .zip(target.data)
     .map(|(&p, &t)| {
-      if p <= 0.0 {
+      if p <= T::ZERO {
         return Err(Error::InsufficientData);
       }
       Ok(t * ln(p))
     })
-    .collect::<Result<Vec<Scalar>, Error>>()?
+    .collect::<Result<Vec<T>, Error>>()?
     .iter()
-    .sum::<Scalar>();
+    .sum::<T>();
 
-  Ok(-sum_ce / (n as Scalar))
+  Ok(-sum_ce / T::from_usize(n))
 }
 
 /// Gradient of Cross Entropy: predictions: -(1/n)*(Ti / Pi)
-fn cross_entropy_grad(pred: &Vector, target: &Vector) -> Result<Vector, Error> {
+fn cross_entropy_grad<T: Float>(pred: VectorView<T>, target: VectorView<T>) -> Result<Vector<T>, Error> {
   let n = pred.len();
-  if n != target.len() {
-    return Err(Error::VectorDimensionMismatch);
+  if n != target.len() || n == 0 {
+    return Err(Error::VectorDimensionMismatch { left: n, right: target.len() });
   }
 
-  if n == 0 {
-    return Err(Error::VectorDimensionMismatch);
+  if pred.data.iter().any(|&p| p <= T::ZERO) {
+    return Err(Error::InsufficientData);
   }
 
-  let inv_n = 1.0 / (n as Scalar);
-  let mut grad = Vector::zeroes(n);
-
-  for idx in 0..n {
-    let p = pred.get(idx).unwrap();
-    let t = target.get(idx).unwrap();
-    if p <= 0.0 {
-      return Err(Error::InsufficientData);
-    }
-    grad.set(idx, -inv_n * (t / p))?;
-  }
-  
-  Ok(grad)
+  let inv_n = T::ONE / T::from_usize(n);
+  pred.zip_map(target, |p, t| -inv_n * (t / p))
 }
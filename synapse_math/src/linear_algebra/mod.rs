@@ -5,13 +5,13 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 20 May 2025
-/// Last Modified: 20 May 2025
+/// Last Modified: 17 October 2026
 
 
 pub mod loss;
 pub mod gradient;
 pub mod activation;
 
-pub use loss::{MSE, CrossEntropy};
-pub use gradient::{SGD, Momentum, Adam};
+pub use loss::{MSE, CrossEntropy, LossKind};
+pub use gradient::{SGD, Momentum, Adam, OptimizerKind, OptimizerConfig, Regularization};
 pub use activation::{ReLU, Sigmoid, Tanh};
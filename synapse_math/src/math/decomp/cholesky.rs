@@ -0,0 +1,80 @@
+/// math/decomp/cholesky.rs - Math Engine Cholesky decomposition
+/// 
+/// This file implements the Cholesky factorization `A = LLᵀ`
+/// of symmetric positive-definite matrices and its solver
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+use crate::math::{Matrix, Scalar, Vector};
+
+use super::triangular::{back_substitution, forward_substitution};
+
+/// Cholesky factorization of a symmetric positive-definite matrix
+#[derive(Debug, Clone)]
+pub struct Cholesky {
+  l: Matrix,
+}
+
+impl Cholesky {
+  /// Factorize `mat`, failing with `NotPositiveDefinite` when it is
+  /// not symmetric or a pivot is not strictly positive
+  pub fn new(mat: &Matrix) -> Result<Cholesky, Error> {
+    if mat.rows() != mat.cols() {
+      return Err(Error::MatDimensionMismatch { left: mat.shape(), right: (mat.rows(), mat.rows()) });
+    }
+
+    let n = mat.rows();
+    let tol = super::singular_tol(mat);
+    for i in 0..n {
+      for j in 0..i {
+        if (mat[(i, j)] - mat[(j, i)]).abs() > tol {
+          return Err(Error::NotPositiveDefinite);
+        }
+      }
+    }
+
+    let mut l = Matrix::zeros(n, n);
+    for j in 0..n {
+      let diag = mat[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<Scalar>();
+      if diag <= tol || !diag.is_finite() {
+        return Err(Error::NotPositiveDefinite);
+      }
+      let l_jj = diag.sqrt();
+      l[(j, j)] = l_jj;
+
+      for i in j + 1..n {
+        let s: Scalar = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+        l[(i, j)] = (mat[(i, j)] - s) / l_jj;
+      }
+    }
+
+    Ok(Cholesky { l })
+  }
+
+  /// Lower triangular factor L
+  pub fn l(&self) -> &Matrix {
+    &self.l
+  }
+
+  /// Solve `Ax = b` with `Ly = b` then `Lᵀx = y`
+  pub fn solve(&self, b: &Vector) -> Result<Vector, Error> {
+    let y = forward_substitution(&self.l, b)?;
+    back_substitution(&self.l.transpose(), &y)
+  }
+
+  /// Determinant of A, the squared product of the diagonal of L
+  pub fn det(&self) -> Scalar {
+    let prod: Scalar = (0..self.l.rows()).map(|i| self.l[(i, i)]).product();
+    prod * prod
+  }
+}
+
+impl Matrix {
+  /// Cholesky factorization of a symmetric positive-definite matrix
+  pub fn cholesky(&self) -> Result<Cholesky, Error> {
+    Cholesky::new(self)
+  }
+}
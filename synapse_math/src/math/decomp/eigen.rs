@@ -0,0 +1,126 @@
+/// math/decomp/eigen.rs - Math Engine Symmetric eigendecomposition
+/// 
+/// This file implements the eigendecomposition `A = VΛVᵀ` of
+/// symmetric matrices, such as covariance matrices, with
+/// cyclic Jacobi rotations
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+use crate::math::{Matrix, Scalar, Vector};
+
+/// Sweeps over every off diagonal entry before giving up on convergence
+const MAX_SWEEPS: usize = 60;
+
+/// Eigendecomposition of a symmetric matrix
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen {
+  /// Eigenvalues in descending order
+  pub values: Vector,
+  /// Orthonormal eigenvectors, column `k` goes with `values[k]`
+  pub vectors: Matrix,
+}
+
+impl SymmetricEigen {
+  /// Decompose `mat`, which must be square and symmetric
+  pub fn new(mat: &Matrix) -> Result<SymmetricEigen, Error> {
+    if mat.rows() != mat.cols() {
+      return Err(Error::MatDimensionMismatch { left: mat.shape(), right: (mat.rows(), mat.rows()) });
+    }
+    if mat.data().iter().any(|v| !v.is_finite()) {
+      return Err(Error::InvalidInput("matrix has non finite entries".to_string()));
+    }
+
+    let n = mat.rows();
+    let tol = super::singular_tol(mat);
+    for i in 0..n {
+      for j in 0..i {
+        if (mat[(i, j)] - mat[(j, i)]).abs() > tol {
+          return Err(Error::InvalidInput(format!(
+            "matrix is not symmetric at ({}, {}): {} vs {}", i, j, mat[(i, j)], mat[(j, i)],
+          )));
+        }
+      }
+    }
+
+    let mut a = mat.clone();
+    let mut v = Matrix::identity(n);
+    let frob_sq: Scalar = mat.data().iter().map(|x| x * x).sum();
+    // Rounding leaves about EPSILON of every entry off the diagonal
+    let off_tol = (n as Scalar * Scalar::EPSILON).powi(2) * frob_sq;
+
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+      let off: Scalar = (0..n).map(|i| (0..i).map(|j| a[(i, j)] * a[(i, j)]).sum::<Scalar>()).sum();
+      if off <= off_tol {
+        converged = true;
+        break;
+      }
+
+      for p in 0..n {
+        for q in p + 1..n {
+          // Entries negligible next to their diagonal are already as small
+          // as a rotation would leave them
+          if a[(p, q)].abs() <= Scalar::EPSILON * (a[(p, p)] * a[(q, q)]).abs().sqrt() {
+            a[(p, q)] = 0.0;
+            a[(q, p)] = 0.0;
+            continue;
+          }
+
+          // Rotation J such that (JᵀAJ)[p, q] = 0
+          let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+          let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+          let c = 1.0 / (t * t + 1.0).sqrt();
+          let s = t * c;
+
+          for k in 0..n {
+            let (akp, akq) = (a[(k, p)], a[(k, q)]);
+            a[(k, p)] = c * akp - s * akq;
+            a[(k, q)] = s * akp + c * akq;
+          }
+          for k in 0..n {
+            let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+            a[(p, k)] = c * apk - s * aqk;
+            a[(q, k)] = s * apk + c * aqk;
+          }
+          for k in 0..n {
+            let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+            v[(k, p)] = c * vkp - s * vkq;
+            v[(k, q)] = s * vkp + c * vkq;
+          }
+          // Zero by construction, drop the rounding noise left by the updates
+          a[(p, q)] = 0.0;
+          a[(q, p)] = 0.0;
+        }
+      }
+    }
+    if !converged {
+      return Err(Error::InvalidInput(format!(
+        "eigendecomposition did not converge in {} sweeps", MAX_SWEEPS,
+      )));
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[(j, j)].total_cmp(&a[(i, i)]));
+
+    let mut values = Vector::zeroes(n);
+    let mut vectors = Matrix::zeros(n, n);
+    for (k, &src) in order.iter().enumerate() {
+      values[k] = a[(src, src)];
+      for i in 0..n {
+        vectors[(i, k)] = v[(i, src)];
+      }
+    }
+
+    Ok(SymmetricEigen { values, vectors })
+  }
+}
+
+impl Matrix {
+  /// Eigenvalues (descending) and orthonormal eigenvectors of a symmetric matrix
+  pub fn symmetric_eigen(&self) -> Result<SymmetricEigen, Error> {
+    SymmetricEigen::new(self)
+  }
+}
@@ -0,0 +1,190 @@
+/// math/decomp/lu.rs - Math Engine LU decomposition
+/// 
+/// This file implements the LU factorization with partial
+/// pivoting `PA = LU` and the determinant, inverse and
+/// linear solve built on it
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+use crate::math::{Matrix, Scalar, Vector};
+
+/// LU factorization of a square matrix with row pivoting
+#[derive(Debug, Clone)]
+pub struct Lu {
+  /// L below the diagonal (unit diagonal implied) and U on and above it
+  factors: Matrix,
+  /// Row `i` of PA is row `perm[i]` of A
+  perm: Vec<usize>,
+  /// Sign of the permutation, flips on every row swap
+  sign: Scalar,
+  /// Pivots at or below this magnitude make the matrix singular
+  tol: Scalar,
+}
+
+impl Lu {
+  /// Factorize a square matrix, this never fails on singular input,
+  /// only solving with the factors does
+  pub fn new(mat: &Matrix) -> Result<Lu, Error> {
+    if mat.rows() != mat.cols() {
+      return Err(Error::MatDimensionMismatch { left: mat.shape(), right: (mat.rows(), mat.rows()) });
+    }
+
+    let n = mat.rows();
+    let mut factors = mat.clone();
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut sign = 1.0;
+
+    for k in 0..n {
+      // Partial pivoting: largest magnitude in column k at or below the diagonal
+      let pivot_row = (k..n)
+        .max_by(|&a, &b| factors[(a, k)].abs().total_cmp(&factors[(b, k)].abs()))
+        .unwrap_or(k);
+      if pivot_row != k {
+        for j in 0..n {
+          factors.data_mut().swap(k * n + j, pivot_row * n + j);
+        }
+        perm.swap(k, pivot_row);
+        sign = -sign;
+      }
+
+      let pivot = factors[(k, k)];
+      if pivot == 0.0 {
+        continue;
+      }
+
+      for i in k + 1..n {
+        let factor = factors[(i, k)] / pivot;
+        factors[(i, k)] = factor;
+        for j in k + 1..n {
+          factors[(i, j)] -= factor * factors[(k, j)];
+        }
+      }
+    }
+
+    let tol = super::singular_tol(mat);
+    Ok(Lu { factors, perm, sign, tol })
+  }
+
+  /// Unit lower triangular factor L
+  pub fn l(&self) -> Matrix {
+    let n = self.factors.rows();
+    let mut l = Matrix::identity(n);
+    for i in 0..n {
+      for j in 0..i {
+        l[(i, j)] = self.factors[(i, j)];
+      }
+    }
+    l
+  }
+
+  /// Upper triangular factor U
+  pub fn u(&self) -> Matrix {
+    let n = self.factors.rows();
+    let mut u = Matrix::zeros(n, n);
+    for i in 0..n {
+      for j in i..n {
+        u[(i, j)] = self.factors[(i, j)];
+      }
+    }
+    u
+  }
+
+  /// Row permutation, row `i` of PA is row `perm()[i]` of A
+  pub fn perm(&self) -> &[usize] {
+    &self.perm
+  }
+
+  /// Permutation matrix P such that PA = LU
+  pub fn p(&self) -> Matrix {
+    let n = self.perm.len();
+    let mut p = Matrix::zeros(n, n);
+    for (i, &src) in self.perm.iter().enumerate() {
+      p[(i, src)] = 1.0;
+    }
+    p
+  }
+
+  /// Determinant, exactly 0 only if a pivot is exactly 0
+  pub fn det(&self) -> Scalar {
+    let n = self.factors.rows();
+    (0..n).fold(self.sign, |acc, i| acc * self.factors[(i, i)])
+  }
+
+  /// Whether a pivot is zero or negligible next to the matrix entries
+  pub fn is_singular(&self) -> bool {
+    let n = self.factors.rows();
+    (0..n).any(|i| self.factors[(i, i)].abs() <= self.tol)
+  }
+
+  /// Solve `Ax = b`
+  pub fn solve(&self, b: &Vector) -> Result<Vector, Error> {
+    let n = self.factors.rows();
+    if b.len() != n {
+      return Err(Error::MatDimensionMismatch { left: self.factors.shape(), right: (b.len(), 1) });
+    }
+    if self.is_singular() {
+      return Err(Error::SingularMatrix);
+    }
+
+    // Forward substitution Ly = Pb
+    let mut x = Vector { data: self.perm.iter().map(|&src| b[src]).collect() };
+    for i in 0..n {
+      for j in 0..i {
+        x[i] -= self.factors[(i, j)] * x[j];
+      }
+    }
+
+    // Back substitution Ux = y
+    for i in (0..n).rev() {
+      for j in i + 1..n {
+        x[i] -= self.factors[(i, j)] * x[j];
+      }
+      x[i] /= self.factors[(i, i)];
+    }
+
+    Ok(x)
+  }
+
+  /// Inverse of A, solving for every column of the identity
+  pub fn inverse(&self) -> Result<Matrix, Error> {
+    let n = self.factors.rows();
+    let mut inv = Matrix::zeros(n, n);
+
+    let mut e = Vector::zeroes(n);
+    for col in 0..n {
+      e[col] = 1.0;
+      let x = self.solve(&e)?;
+      e[col] = 0.0;
+      for row in 0..n {
+        inv[(row, col)] = x[row];
+      }
+    }
+
+    Ok(inv)
+  }
+}
+
+impl Matrix {
+  /// LU factorization with partial pivoting of a square matrix
+  pub fn lu(&self) -> Result<Lu, Error> {
+    Lu::new(self)
+  }
+
+  /// Solve `self * x = b` for a square, non singular matrix
+  pub fn solve(&self, b: &Vector) -> Result<Vector, Error> {
+    self.lu()?.solve(b)
+  }
+
+  /// Inverse of a square, non singular matrix
+  pub fn inverse(&self) -> Result<Matrix, Error> {
+    self.lu()?.inverse()
+  }
+
+  /// Determinant of a square matrix
+  pub fn det(&self) -> Result<Scalar, Error> {
+    Ok(self.lu()?.det())
+  }
+}
@@ -0,0 +1,32 @@
+/// math/decomp/mod.rs - Math Engine Matrix decompositions
+/// 
+/// This module exposes the matrix factorizations and
+/// the solvers built on them
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+
+pub mod lu;
+pub mod qr;
+pub mod cholesky;
+pub mod svd;
+pub mod eigen;
+pub mod triangular;
+
+pub use lu::Lu;
+pub use qr::{Qr, Lstsq, lstsq};
+pub use cholesky::Cholesky;
+pub use svd::Svd;
+pub use eigen::SymmetricEigen;
+pub use triangular::{forward_substitution, back_substitution};
+
+use super::{Matrix, Scalar};
+
+/// Pivots at or below this threshold are treated as zero, relative
+/// to the size and largest entry of the factorized matrix
+pub(crate) fn singular_tol(mat: &Matrix) -> Scalar {
+  let max_abs = mat.data().iter().fold(0.0, |acc: Scalar, v| acc.max(v.abs()));
+  (mat.rows().max(mat.cols()) as Scalar) * Scalar::EPSILON * max_abs
+}
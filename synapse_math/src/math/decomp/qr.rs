@@ -0,0 +1,194 @@
+/// math/decomp/qr.rs - Math Engine QR decomposition
+/// 
+/// This file implements the Householder QR factorization
+/// `A = QR` and the least-squares solver built on its
+/// column pivoted variant
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+use crate::math::{Matrix, MatrixView, Scalar, Vector, VectorView};
+
+/// Householder QR factorization of an (m, n) matrix, optionally
+/// with column pivoting so that `AP = QR`
+#[derive(Debug, Clone)]
+pub struct Qr {
+  rows: usize,
+  cols: usize,
+  /// R on and above the diagonal, the rest is unused
+  r: Matrix,
+  /// Reflector `I - beta * v vᵀ` of each step, `v` starting at row `step`
+  reflectors: Vec<(Vector, Scalar)>,
+  /// Column `j` of AP is column `perm[j]` of A
+  perm: Vec<usize>,
+}
+
+/// Least-squares solution of `Ax ≈ b`
+#[derive(Debug, Clone)]
+pub struct Lstsq {
+  /// Minimizer of `||Ax - b||`, columns beyond the rank are set to 0
+  pub x: Vector,
+  /// Residual sum of squares `||Ax - b||²`
+  pub residual: Scalar,
+  /// Numerical rank of A
+  pub rank: usize,
+}
+
+impl Qr {
+  /// Householder QR of `mat`, with column pivoting when `pivot` is set
+  pub fn new(mat: MatrixView, pivot: bool) -> Qr {
+    let (m, n) = mat.shape();
+    let mut r = mat.to_matrix();
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut reflectors = Vec::with_capacity(m.min(n));
+
+    for k in 0..m.min(n) {
+      if pivot {
+        // Bring forward the remaining column with the largest norm below row k
+        let norm_sq = |r: &Matrix, j: usize| (k..m).map(|i| r[(i, j)] * r[(i, j)]).sum::<Scalar>();
+        let best = (k..n)
+          .max_by(|&a, &b| norm_sq(&r, a).total_cmp(&norm_sq(&r, b)))
+          .unwrap_or(k);
+        if best != k {
+          for i in 0..m {
+            r.data_mut().swap(i * n + k, i * n + best);
+          }
+          perm.swap(k, best);
+        }
+      }
+
+      // Reflect column k below the diagonal onto alpha·e1
+      let mut v = Vector { data: (k..m).map(|i| r[(i, k)]).collect() };
+      let norm = v.data.iter().map(|x| x * x).sum::<Scalar>().sqrt();
+      if norm == 0.0 {
+        reflectors.push((v, 0.0));
+        continue;
+      }
+      let alpha = if v[0] > 0.0 { -norm } else { norm };
+      v[0] -= alpha;
+      let beta = 2.0 / v.data.iter().map(|x| x * x).sum::<Scalar>();
+
+      for j in k..n {
+        let s: Scalar = (k..m).map(|i| v[i - k] * r[(i, j)]).sum();
+        for i in k..m {
+          r[(i, j)] -= beta * s * v[i - k];
+        }
+      }
+      r[(k, k)] = alpha;
+      for i in k + 1..m {
+        r[(i, k)] = 0.0;
+      }
+
+      reflectors.push((v, beta));
+    }
+
+    Qr { rows: m, cols: n, r, reflectors, perm }
+  }
+
+  /// Thin orthonormal factor Q of shape (m, min(m, n))
+  pub fn q(&self) -> Matrix {
+    let k = self.rows.min(self.cols);
+    let mut q = Matrix::zeros(self.rows, k);
+    for j in 0..k {
+      q[(j, j)] = 1.0;
+    }
+
+    // Q = H0 H1 ... applied to the first k columns of the identity
+    for (step, (v, beta)) in self.reflectors.iter().enumerate().rev() {
+      for j in 0..k {
+        let s: Scalar = (step..self.rows).map(|i| v[i - step] * q[(i, j)]).sum();
+        for i in step..self.rows {
+          q[(i, j)] -= beta * s * v[i - step];
+        }
+      }
+    }
+
+    q
+  }
+
+  /// Upper triangular (trapezoidal if wide) factor R of shape (min(m, n), n)
+  pub fn r(&self) -> Matrix {
+    let k = self.rows.min(self.cols);
+    let mut r = Matrix::zeros(k, self.cols);
+    for i in 0..k {
+      for j in i..self.cols {
+        r[(i, j)] = self.r[(i, j)];
+      }
+    }
+    r
+  }
+
+  /// Column permutation, column `j` of AP is column `perm()[j]` of A
+  pub fn perm(&self) -> &[usize] {
+    &self.perm
+  }
+
+  /// Compute Qᵀb in place for a vector of length m
+  fn apply_qt(&self, b: &mut Vector) {
+    for (step, (v, beta)) in self.reflectors.iter().enumerate() {
+      let s: Scalar = (step..self.rows).map(|i| v[i - step] * b[i]).sum();
+      for i in step..self.rows {
+        b[i] -= beta * s * v[i - step];
+      }
+    }
+  }
+
+  /// Number of diagonal entries of R above the rank tolerance, only
+  /// meaningful for the pivoted factorization where they decrease
+  pub fn rank(&self) -> usize {
+    let k = self.rows.min(self.cols);
+    if k == 0 {
+      return 0;
+    }
+    let tol = (self.rows.max(self.cols) as Scalar) * Scalar::EPSILON * self.r[(0, 0)].abs();
+    (0..k).take_while(|&i| self.r[(i, i)].abs() > tol).count()
+  }
+}
+
+/// Least-squares solution of `ax ≈ b` through column pivoted QR,
+/// never forming `aᵀa`. Rank deficient and wide systems get the
+/// basic solution with the dependent columns set to 0
+pub fn lstsq<'a, 'b>(a: impl Into<MatrixView<'a>>, b: impl Into<VectorView<'b>>) -> Result<Lstsq, Error> {
+  let (a, b) = (a.into(), b.into());
+  let (m, n) = a.shape();
+  if b.len() != m {
+    return Err(Error::MatDimensionMismatch { left: (m, n), right: (b.len(), 1) });
+  }
+  if m == 0 || n == 0 {
+    return Err(Error::InsufficientData);
+  }
+
+  let qr = Qr::new(a, true);
+  let rank = qr.rank();
+  let mut c = b.to_vector();
+  qr.apply_qt(&mut c);
+
+  // Back substitution on the leading rank x rank block of R
+  let mut z = vec![0.0; rank];
+  for i in (0..rank).rev() {
+    let s: Scalar = (i + 1..rank).map(|j| qr.r[(i, j)] * z[j]).sum();
+    z[i] = (c[i] - s) / qr.r[(i, i)];
+  }
+
+  let mut x = Vector::zeroes(n);
+  for (j, &val) in z.iter().enumerate() {
+    x[qr.perm[j]] = val;
+  }
+  let residual = c.data[rank..].iter().map(|v| v * v).sum();
+
+  Ok(Lstsq { x, residual, rank })
+}
+
+impl Matrix {
+  /// Householder QR factorization `self = QR`
+  pub fn qr(&self) -> Qr {
+    Qr::new(self.view(), false)
+  }
+
+  /// Least-squares solution of `self * x ≈ b`, see `decomp::lstsq`
+  pub fn lstsq(&self, b: &Vector) -> Result<Lstsq, Error> {
+    lstsq(self, b)
+  }
+}
@@ -0,0 +1,171 @@
+/// math/decomp/svd.rs - Math Engine Singular value decomposition
+/// 
+/// This file implements the thin SVD `A = UΣVᵀ` with one-sided
+/// Jacobi rotations and the pseudo-inverse, rank and condition
+/// number built on it
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+use crate::math::{Matrix, Scalar, Vector};
+
+/// Sweeps over every column pair before giving up on convergence
+const MAX_SWEEPS: usize = 60;
+
+/// Thin SVD of an (m, n) matrix with k = min(m, n)
+#[derive(Debug, Clone)]
+pub struct Svd {
+  /// (m, k) left singular vectors, columns of zero singular values are 0
+  pub u: Matrix,
+  /// k singular values in descending order
+  pub s: Vector,
+  /// (k, n) right singular vectors as rows
+  pub vt: Matrix,
+}
+
+impl Svd {
+  /// Decompose `mat`, failing on non finite entries
+  pub fn new(mat: &Matrix) -> Result<Svd, Error> {
+    if mat.data().iter().any(|v| !v.is_finite()) {
+      return Err(Error::InvalidInput("matrix has non finite entries".to_string()));
+    }
+
+    // One-sided Jacobi orthogonalizes columns, so work on the
+    // transpose of wide matrices and swap the factors back
+    if mat.rows() < mat.cols() {
+      let Svd { u, s, vt } = Svd::new(&mat.transpose())?;
+      return Ok(Svd { u: vt.transpose(), s, vt: u.transpose() });
+    }
+
+    let (m, n) = mat.shape();
+    // Columns of A, rotated in place until mutually orthogonal
+    let mut cols: Vec<Vec<Scalar>> = (0..n).map(|j| (0..m).map(|i| mat[(i, j)]).collect()).collect();
+    let mut v: Vec<Vec<Scalar>> = (0..n).map(|j| (0..n).map(|i| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+      converged = true;
+      for p in 0..n {
+        for q in p + 1..n {
+          let alpha: Scalar = cols[p].iter().map(|x| x * x).sum();
+          let beta: Scalar = cols[q].iter().map(|x| x * x).sum();
+          let gamma: Scalar = cols[p].iter().zip(&cols[q]).map(|(x, y)| x * y).sum();
+          if gamma.abs() <= Scalar::EPSILON * (alpha * beta).sqrt() {
+            continue;
+          }
+          converged = false;
+
+          // Rotation zeroing the (p, q) entry of AᵀA
+          let zeta = (beta - alpha) / (2.0 * gamma);
+          let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+          let c = 1.0 / (1.0 + t * t).sqrt();
+          let s = c * t;
+
+          for vecs in [&mut cols, &mut v] {
+            let (left, right) = vecs.split_at_mut(q);
+            for (x, y) in left[p].iter_mut().zip(right[0].iter_mut()) {
+              let (xp, xq) = (*x, *y);
+              *x = c * xp - s * xq;
+              *y = s * xp + c * xq;
+            }
+          }
+        }
+      }
+      if converged {
+        break;
+      }
+    }
+    if !converged {
+      return Err(Error::InvalidInput(format!("SVD did not converge in {} sweeps", MAX_SWEEPS)));
+    }
+
+    let norms: Vec<Scalar> = cols.iter().map(|c| c.iter().map(|x| x * x).sum::<Scalar>().sqrt()).collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| norms[b].total_cmp(&norms[a]));
+
+    let mut u = Matrix::zeros(m, n);
+    let mut s = Vector::zeroes(n);
+    let mut vt = Matrix::zeros(n, n);
+    for (k, &j) in order.iter().enumerate() {
+      s[k] = norms[j];
+      if norms[j] > 0.0 {
+        for i in 0..m {
+          u[(i, k)] = cols[j][i] / norms[j];
+        }
+      }
+      for i in 0..n {
+        vt[(k, i)] = v[j][i];
+      }
+    }
+
+    Ok(Svd { u, s, vt })
+  }
+
+  /// Singular values at or below this threshold are treated as zero
+  pub fn tol(&self) -> Scalar {
+    let largest = self.s.data.first().copied().unwrap_or(0.0);
+    (self.u.rows().max(self.vt.cols()) as Scalar) * Scalar::EPSILON * largest
+  }
+
+  /// Number of singular values above `tol`
+  pub fn rank(&self) -> usize {
+    let tol = self.tol();
+    self.s.data.iter().filter(|&&s| s > tol).count()
+  }
+
+  /// Ratio of the largest to the smallest singular value in the 2-norm,
+  /// infinite for rank deficient matrices
+  pub fn cond(&self) -> Scalar {
+    match (self.s.data.first(), self.s.data.last()) {
+      (Some(&max), Some(&min)) if min > 0.0 => max / min,
+      (Some(_), Some(_)) => Scalar::INFINITY,
+      _ => 0.0,
+    }
+  }
+
+  /// Moore-Penrose pseudo-inverse `VΣ⁺Uᵀ`, singular values at
+  /// or below `tol` are treated as zero
+  pub fn pinv(&self) -> Matrix {
+    let (m, n) = (self.u.rows(), self.vt.cols());
+    let tol = self.tol();
+    let mut pinv = Matrix::zeros(n, m);
+
+    for (k, &s) in self.s.data.iter().enumerate() {
+      if s <= tol {
+        continue;
+      }
+      for i in 0..n {
+        let v_ik = self.vt[(k, i)] / s;
+        for j in 0..m {
+          pinv[(i, j)] += v_ik * self.u[(j, k)];
+        }
+      }
+    }
+
+    pinv
+  }
+}
+
+impl Matrix {
+  /// Thin singular value decomposition
+  pub fn svd(&self) -> Result<Svd, Error> {
+    Svd::new(self)
+  }
+
+  /// Moore-Penrose pseudo-inverse
+  pub fn pinv(&self) -> Result<Matrix, Error> {
+    Ok(self.svd()?.pinv())
+  }
+
+  /// Numerical rank, the number of non negligible singular values
+  pub fn rank(&self) -> Result<usize, Error> {
+    Ok(self.svd()?.rank())
+  }
+
+  /// 2-norm condition number, large values flag collinear columns
+  pub fn cond(&self) -> Result<Scalar, Error> {
+    Ok(self.svd()?.cond())
+  }
+}
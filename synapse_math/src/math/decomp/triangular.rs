@@ -0,0 +1,51 @@
+/// math/decomp/triangular.rs - Math Engine triangular solvers
+/// 
+/// This file implements forward and back substitution
+/// for lower and upper triangular systems
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+use crate::math::{Matrix, Scalar, Vector};
+
+fn check_system(mat: &Matrix, b: &Vector) -> Result<(), Error> {
+  if mat.rows() != mat.cols() {
+    return Err(Error::MatDimensionMismatch { left: mat.shape(), right: (mat.rows(), mat.rows()) });
+  }
+  if b.len() != mat.rows() {
+    return Err(Error::MatDimensionMismatch { left: mat.shape(), right: (b.len(), 1) });
+  }
+  if (0..mat.rows()).any(|i| mat[(i, i)] == 0.0) {
+    return Err(Error::SingularMatrix);
+  }
+  Ok(())
+}
+
+/// Solve `lx = b` by forward substitution, only the lower
+/// triangle of `l` (diagonal included) is read
+pub fn forward_substitution(l: &Matrix, b: &Vector) -> Result<Vector, Error> {
+  check_system(l, b)?;
+
+  let mut x = b.clone();
+  for i in 0..l.rows() {
+    let s: Scalar = (0..i).map(|j| l[(i, j)] * x[j]).sum();
+    x[i] = (x[i] - s) / l[(i, i)];
+  }
+  Ok(x)
+}
+
+/// Solve `ux = b` by back substitution, only the upper
+/// triangle of `u` (diagonal included) is read
+pub fn back_substitution(u: &Matrix, b: &Vector) -> Result<Vector, Error> {
+  check_system(u, b)?;
+
+  let n = u.rows();
+  let mut x = b.clone();
+  for i in (0..n).rev() {
+    let s: Scalar = (i + 1..n).map(|j| u[(i, j)] * x[j]).sum();
+    x[i] = (x[i] - s) / u[(i, i)];
+  }
+  Ok(x)
+}
@@ -1,40 +1,60 @@
 /// math/elem.rs - Math Engine Element wise module
 /// 
-/// This module exposes core math Scalar
-/// inline functions
+/// This module exposes core math inline functions
+/// over any Float
+/// 
+/// Every function also exists as a method on Vector and
+/// Matrix applying it to the whole container
 /// 
 /// Author: Fedi Nabli
 /// Date: 19 May 2025
-/// Last Modified: 19 May 2025
+/// Last Modified: 17 October 2026
 
-use super::Scalar;
+use super::{Float, Matrix, Vector};
 
 #[inline]
-pub fn exp(x: Scalar) -> Scalar { x.exp() }
+pub fn exp<T: Float>(x: T) -> T { x.exp() }
 
 #[inline]
-pub fn ln(x: Scalar) -> Scalar { x.ln() }
+pub fn ln<T: Float>(x: T) -> T { x.ln() }
 
 #[inline]
-pub fn sqrt(x: Scalar) -> Scalar { x.sqrt() }
+pub fn sqrt<T: Float>(x: T) -> T { x.sqrt() }
 
 #[inline]
-pub fn abs(x: Scalar) -> Scalar { x.abs() }
+pub fn abs<T: Float>(x: T) -> T { x.abs() }
 
 #[inline]
-pub fn cos(x: Scalar) -> Scalar { x.cos() }
+pub fn cos<T: Float>(x: T) -> T { x.cos() }
 
 #[inline]
-pub fn sin(x: Scalar) -> Scalar { x.sin() }
+pub fn sin<T: Float>(x: T) -> T { x.sin() }
 
 #[inline]
-pub fn tan(x: Scalar) -> Scalar { x.tan() }
+pub fn tan<T: Float>(x: T) -> T { x.tan() }
 
 #[inline]
-pub fn cosh(x: Scalar) -> Scalar { x.cosh() }
+pub fn cosh<T: Float>(x: T) -> T { x.cosh() }
 
 #[inline]
-pub fn sinh(x: Scalar) -> Scalar { x.sinh() }
+pub fn sinh<T: Float>(x: T) -> T { x.sinh() }
 
 #[inline]
-pub fn tanh(x: Scalar) -> Scalar { x.tanh() }
+pub fn tanh<T: Float>(x: T) -> T { x.tanh() }
+
+/// Whole container version of each element wise function
+macro_rules! container_fns {
+  ($ty:ident, $($name:ident),+) => {
+    impl<T: Float> $ty<T> {
+      $(
+        #[doc = concat!("`", stringify!($name), "` of every entry")]
+        pub fn $name(&self) -> $ty<T> {
+          self.map($name)
+        }
+      )+
+    }
+  };
+}
+
+container_fns!(Vector, exp, ln, sqrt, abs, cos, sin, tan, cosh, sinh, tanh);
+container_fns!(Matrix, exp, ln, sqrt, abs, cos, sin, tan, cosh, sinh, tanh);
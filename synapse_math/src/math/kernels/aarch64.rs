@@ -0,0 +1,87 @@
+/// math/kernels/aarch64.rs - Math Engine NEON kernels
+/// 
+/// This file implements the kernels with 128 bit NEON
+/// registers holding 2 Scalars and fused multiply-add
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::arch::aarch64::*;
+
+use crate::math::Scalar;
+
+/// Scalars per NEON register
+const LANES: usize = 2;
+
+/// `SUM(a[i] * b[i])` over the common length of `a` and `b`
+/// 
+/// # Safety
+/// The CPU must support NEON
+#[target_feature(enable = "neon")]
+pub fn dot(a: &[Scalar], b: &[Scalar]) -> Scalar {
+  let n = a.len().min(b.len());
+  let (a, b) = (a.as_ptr(), b.as_ptr());
+
+  // Four independent accumulators hide the FMA latency
+  let mut acc = [vdupq_n_f64(0.0); 4];
+  let mut i = 0;
+  while i + 4 * LANES <= n {
+    for (lane, acc) in acc.iter_mut().enumerate() {
+      let offset = i + lane * LANES;
+      // Safety: offset + LANES <= n
+      let (x, y) = unsafe { (vld1q_f64(a.add(offset)), vld1q_f64(b.add(offset))) };
+      *acc = vfmaq_f64(*acc, x, y);
+    }
+    i += 4 * LANES;
+  }
+  while i + LANES <= n {
+    // Safety: i + LANES <= n
+    let (x, y) = unsafe { (vld1q_f64(a.add(i)), vld1q_f64(b.add(i))) };
+    acc[0] = vfmaq_f64(acc[0], x, y);
+    i += LANES;
+  }
+
+  let sum = vaddq_f64(vaddq_f64(acc[0], acc[1]), vaddq_f64(acc[2], acc[3]));
+  let mut total = vaddvq_f64(sum);
+
+  for j in i..n {
+    // Safety: j < n
+    total += unsafe { *a.add(j) * *b.add(j) };
+  }
+  total
+}
+
+/// `y += alpha * x` over the common length of `x` and `y`
+/// 
+/// # Safety
+/// The CPU must support NEON
+#[target_feature(enable = "neon")]
+pub fn axpy(alpha: Scalar, x: &[Scalar], y: &mut [Scalar]) {
+  let n = x.len().min(y.len());
+  let (x, y) = (x.as_ptr(), y.as_mut_ptr());
+  let alpha_v = vdupq_n_f64(alpha);
+
+  let mut i = 0;
+  while i + LANES <= n {
+    // Safety: i + LANES <= n for both slices
+    unsafe {
+      let res = vfmaq_f64(vld1q_f64(y.add(i)), alpha_v, vld1q_f64(x.add(i)));
+      vst1q_f64(y.add(i), res);
+    }
+    i += LANES;
+  }
+  for j in i..n {
+    // Safety: j < n
+    unsafe { *y.add(j) += alpha * *x.add(j) };
+  }
+}
+
+/// Blocked `out += a * b`, see `kernels::gemm`
+/// 
+/// # Safety
+/// The CPU must support NEON
+#[target_feature(enable = "neon")]
+pub fn gemm(a: &[Scalar], b: &[Scalar], out: &mut [Scalar], m: usize, k: usize, n: usize) {
+  super::blocked_gemm(a, b, out, m, k, n, |alpha, x, y| axpy(alpha, x, y));
+}
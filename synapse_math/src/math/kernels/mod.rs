@@ -0,0 +1,214 @@
+/// math/kernels/mod.rs - Math Engine compute kernels
+/// 
+/// This module exposes the dot product, axpy, matrix-vector,
+/// matrix-matrix and transpose kernels used by Vector and Matrix,
+/// picking AVX2/FMA or NEON at runtime and falling back to
+/// portable code. Matrix kernels split their output rows across
+/// threads once the matrices are large enough (see `parallel`)
+/// 
+/// Kernels work on raw row-major slices and expect the caller
+/// to have checked the shapes already. The SIMD backends are f64
+/// only, f32 always runs the portable kernels
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+
+pub mod portable;
+pub mod parallel;
+#[cfg(target_arch = "x86_64")]
+pub mod x86_64;
+#[cfg(target_arch = "aarch64")]
+pub mod aarch64;
+
+use super::{Float, Scalar};
+
+pub use parallel::{set_num_threads, with_num_threads, num_threads};
+
+/// Rows of `a` handled per block of `gemm`
+const BLOCK_ROWS: usize = 64;
+/// Shared dimension handled per block, keeps a panel of `b` in cache
+const BLOCK_INNER: usize = 256;
+/// Columns of `b` and `out` handled per block
+const BLOCK_COLS: usize = 512;
+
+/// Instruction set used by the kernels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+  Portable,
+  Avx2Fma,
+  Neon,
+}
+
+/// Fastest backend supported by the running CPU
+pub fn backend() -> Backend {
+  #[cfg(target_arch = "x86_64")]
+  if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma") {
+    return Backend::Avx2Fma;
+  }
+
+  #[cfg(target_arch = "aarch64")]
+  if std::arch::is_aarch64_feature_detected!("neon") {
+    return Backend::Neon;
+  }
+
+  Backend::Portable
+}
+
+/// Element type specific part of the kernels
+pub trait Kernel: Sized {
+  /// `SUM(a[i] * b[i])` over two slices of the same length
+  fn dot(a: &[Self], b: &[Self]) -> Self;
+  /// `y += alpha * x` over two slices of the same length
+  fn axpy(alpha: Self, x: &[Self], y: &mut [Self]);
+  /// Single threaded `out += a * b`, see `gemm`
+  fn gemm_serial(a: &[Self], b: &[Self], out: &mut [Self], m: usize, k: usize, n: usize);
+}
+
+impl Kernel for f64 {
+  fn dot(a: &[Scalar], b: &[Scalar]) -> Scalar {
+    match backend() {
+      #[cfg(target_arch = "x86_64")]
+      // Safety: AVX2 and FMA were detected at runtime
+      Backend::Avx2Fma => unsafe { x86_64::dot(a, b) },
+      #[cfg(target_arch = "aarch64")]
+      // Safety: NEON was detected at runtime
+      Backend::Neon => unsafe { aarch64::dot(a, b) },
+      _ => portable::dot(a, b),
+    }
+  }
+
+  fn axpy(alpha: Scalar, x: &[Scalar], y: &mut [Scalar]) {
+    match backend() {
+      #[cfg(target_arch = "x86_64")]
+      // Safety: AVX2 and FMA were detected at runtime
+      Backend::Avx2Fma => unsafe { x86_64::axpy(alpha, x, y) },
+      #[cfg(target_arch = "aarch64")]
+      // Safety: NEON was detected at runtime
+      Backend::Neon => unsafe { aarch64::axpy(alpha, x, y) },
+      _ => portable::axpy(alpha, x, y),
+    }
+  }
+
+  fn gemm_serial(a: &[Scalar], b: &[Scalar], out: &mut [Scalar], m: usize, k: usize, n: usize) {
+    match backend() {
+      #[cfg(target_arch = "x86_64")]
+      // Safety: AVX2 and FMA were detected at runtime
+      Backend::Avx2Fma => unsafe { x86_64::gemm(a, b, out, m, k, n) },
+      #[cfg(target_arch = "aarch64")]
+      // Safety: NEON was detected at runtime
+      Backend::Neon => unsafe { aarch64::gemm(a, b, out, m, k, n) },
+      _ => blocked_gemm(a, b, out, m, k, n, portable::axpy),
+    }
+  }
+}
+
+impl Kernel for f32 {
+  fn dot(a: &[f32], b: &[f32]) -> f32 {
+    portable::dot(a, b)
+  }
+
+  fn axpy(alpha: f32, x: &[f32], y: &mut [f32]) {
+    portable::axpy(alpha, x, y)
+  }
+
+  fn gemm_serial(a: &[f32], b: &[f32], out: &mut [f32], m: usize, k: usize, n: usize) {
+    blocked_gemm(a, b, out, m, k, n, portable::axpy)
+  }
+}
+
+/// `SUM(a[i] * b[i])` over two slices of the same length
+pub fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+  debug_assert_eq!(a.len(), b.len());
+  T::dot(a, b)
+}
+
+/// `y += alpha * x` over two slices of the same length
+pub fn axpy<T: Float>(alpha: T, x: &[T], y: &mut [T]) {
+  debug_assert_eq!(x.len(), y.len());
+  T::axpy(alpha, x, y)
+}
+
+/// `out = a * x` for a row-major (out.len(), x.len()) matrix `a`
+pub fn gemv<T: Float>(a: &[T], x: &[T], out: &mut [T]) {
+  let cols = x.len();
+  debug_assert_eq!(a.len(), out.len() * cols);
+
+  if cols == 0 {
+    out.fill(T::ZERO);
+    return;
+  }
+  parallel::for_row_chunks(out, 1, cols, |first, out| {
+    let a = &a[first * cols..(first + out.len()) * cols];
+    for (row, res) in a.chunks_exact(cols).zip(out.iter_mut()) {
+      *res = dot(row, x);
+    }
+  });
+}
+
+/// `out += a * b` for row-major `a` (m, k), `b` (k, n) and `out` (m, n)
+/// 
+/// Blocks over all three dimensions so the panel of `b` being
+/// streamed stays in cache, the inner loop is an axpy on
+/// contiguous rows of `b` and `out`
+pub fn gemm<T: Float>(a: &[T], b: &[T], out: &mut [T], m: usize, k: usize, n: usize) {
+  debug_assert_eq!(a.len(), m * k);
+  debug_assert_eq!(b.len(), k * n);
+  debug_assert_eq!(out.len(), m * n);
+
+  if n == 0 {
+    return;
+  }
+  parallel::for_row_chunks(out, n, k * n, |first, out| {
+    let rows = out.len() / n;
+    T::gemm_serial(&a[first * k..(first + rows) * k], b, out, rows, k, n);
+  });
+}
+
+/// `out = aᵀ` for a row-major (rows, cols) matrix `a`, `out` being (cols, rows)
+pub fn transpose<T: Float>(a: &[T], rows: usize, cols: usize, out: &mut [T]) {
+  debug_assert_eq!(a.len(), rows * cols);
+  debug_assert_eq!(out.len(), rows * cols);
+
+  if rows == 0 {
+    return;
+  }
+  // Row j of the output is column j of `a`
+  parallel::for_row_chunks(out, rows, rows, |first, out| {
+    for (j, out_row) in out.chunks_exact_mut(rows).enumerate() {
+      for (i, val) in out_row.iter_mut().enumerate() {
+        *val = a[i * cols + first + j];
+      }
+    }
+  });
+}
+
+/// Cache blocked loop shared by every backend of `gemm`
+#[inline(always)]
+fn blocked_gemm<T: Copy>(
+  a: &[T],
+  b: &[T],
+  out: &mut [T],
+  m: usize,
+  k: usize,
+  n: usize,
+  mut axpy: impl FnMut(T, &[T], &mut [T]),
+) {
+  for i0 in (0..m).step_by(BLOCK_ROWS) {
+    let i1 = (i0 + BLOCK_ROWS).min(m);
+    for p0 in (0..k).step_by(BLOCK_INNER) {
+      let p1 = (p0 + BLOCK_INNER).min(k);
+      for j0 in (0..n).step_by(BLOCK_COLS) {
+        let j1 = (j0 + BLOCK_COLS).min(n);
+
+        for i in i0..i1 {
+          let out_row = &mut out[i * n + j0..i * n + j1];
+          for p in p0..p1 {
+            axpy(a[i * k + p], &b[p * n + j0..p * n + j1], out_row);
+          }
+        }
+      }
+    }
+  }
+}
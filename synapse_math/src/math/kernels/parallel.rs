@@ -0,0 +1,87 @@
+/// math/kernels/parallel.rs - Math Engine kernel threading
+/// 
+/// This file holds the thread count used by the kernels
+/// and splits their output rows across scoped threads
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Multiply-adds (or copies) below which a thread is not worth spawning
+pub const MIN_WORK_PER_THREAD: usize = 1 << 15;
+
+/// Thread count shared by every caller, 0 means one per core
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+  /// Per call override installed by `with_num_threads`
+  static LOCAL_THREADS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Set the thread count used by the kernels on every thread,
+/// 0 uses one thread per available core
+pub fn set_num_threads(n: usize) {
+  NUM_THREADS.store(n, Ordering::Relaxed);
+}
+
+/// Run `f` with the kernels called from this thread using `n` threads,
+/// 0 falls back to the global setting
+pub fn with_num_threads<T>(n: usize, f: impl FnOnce() -> T) -> T {
+  let prev = LOCAL_THREADS.with(|local| local.replace((n > 0).then_some(n)));
+  // Restore on unwind too so a panicking call doesn't leak the override
+  struct Restore(Option<usize>);
+  impl Drop for Restore {
+    fn drop(&mut self) {
+      LOCAL_THREADS.with(|local| local.set(self.0));
+    }
+  }
+  let _restore = Restore(prev);
+
+  f()
+}
+
+/// Thread count the kernels called from this thread will use
+pub fn num_threads() -> usize {
+  let configured = LOCAL_THREADS.with(Cell::get)
+    .unwrap_or_else(|| NUM_THREADS.load(Ordering::Relaxed));
+
+  match configured {
+    0 => thread::available_parallelism().map_or(1, |n| n.get()),
+    n => n,
+  }
+}
+
+/// Call `f(first_row, rows)` on disjoint chunks of the `row_len` wide rows
+/// of `out`, in parallel when `work_per_row` times the row count is worth it
+pub fn for_row_chunks<T, F>(out: &mut [T], row_len: usize, work_per_row: usize, f: F)
+where
+  T: Send,
+  F: Fn(usize, &mut [T]) + Sync,
+{
+  let rows = out.len().checked_div(row_len).unwrap_or(0);
+  let by_work = rows.saturating_mul(work_per_row) / MIN_WORK_PER_THREAD;
+  let threads = num_threads().min(rows).min(by_work);
+
+  if threads <= 1 {
+    f(0, out);
+    return;
+  }
+
+  let rows_per_thread = rows.div_ceil(threads);
+  let f = &f;
+  thread::scope(|scope| {
+    let mut chunks = out.chunks_mut(rows_per_thread * row_len).enumerate();
+    // The calling thread takes the first chunk instead of idling
+    let first = chunks.next();
+    for (idx, chunk) in chunks {
+      scope.spawn(move || f(idx * rows_per_thread, chunk));
+    }
+    if let Some((_, chunk)) = first {
+      f(0, chunk);
+    }
+  });
+}
@@ -0,0 +1,23 @@
+/// math/kernels/portable.rs - Math Engine portable kernels
+/// 
+/// This file implements the scalar fallback of the kernels,
+/// also used as the reference the SIMD versions are tested against
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::math::Float;
+
+pub fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+  a.iter()
+    .zip(b)
+    .map(|(&x, &y)| x * y)
+    .sum()
+}
+
+pub fn axpy<T: Float>(alpha: T, x: &[T], y: &mut [T]) {
+  for (out, &val) in y.iter_mut().zip(x) {
+    *out += alpha * val;
+  }
+}
@@ -0,0 +1,90 @@
+/// math/kernels/x86_64.rs - Math Engine AVX2/FMA kernels
+/// 
+/// This file implements the kernels with 256 bit AVX2
+/// registers holding 4 Scalars and fused multiply-add
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::arch::x86_64::*;
+
+use crate::math::Scalar;
+
+/// Scalars per AVX2 register
+const LANES: usize = 4;
+
+/// `SUM(a[i] * b[i])` over the common length of `a` and `b`
+/// 
+/// # Safety
+/// The CPU must support AVX2 and FMA
+#[target_feature(enable = "avx2,fma")]
+pub fn dot(a: &[Scalar], b: &[Scalar]) -> Scalar {
+  let n = a.len().min(b.len());
+  let (a, b) = (a.as_ptr(), b.as_ptr());
+
+  // Four independent accumulators hide the FMA latency
+  let mut acc = [_mm256_setzero_pd(); 4];
+  let mut i = 0;
+  while i + 4 * LANES <= n {
+    for (lane, acc) in acc.iter_mut().enumerate() {
+      let offset = i + lane * LANES;
+      // Safety: offset + LANES <= n
+      let (x, y) = unsafe { (_mm256_loadu_pd(a.add(offset)), _mm256_loadu_pd(b.add(offset))) };
+      *acc = _mm256_fmadd_pd(x, y, *acc);
+    }
+    i += 4 * LANES;
+  }
+  while i + LANES <= n {
+    // Safety: i + LANES <= n
+    let (x, y) = unsafe { (_mm256_loadu_pd(a.add(i)), _mm256_loadu_pd(b.add(i))) };
+    acc[0] = _mm256_fmadd_pd(x, y, acc[0]);
+    i += LANES;
+  }
+
+  let sum = _mm256_add_pd(_mm256_add_pd(acc[0], acc[1]), _mm256_add_pd(acc[2], acc[3]));
+  let mut lanes = [0.0; LANES];
+  // Safety: lanes holds exactly LANES Scalars
+  unsafe { _mm256_storeu_pd(lanes.as_mut_ptr(), sum) };
+  let mut total = (lanes[0] + lanes[1]) + (lanes[2] + lanes[3]);
+
+  for j in i..n {
+    // Safety: j < n
+    total += unsafe { *a.add(j) * *b.add(j) };
+  }
+  total
+}
+
+/// `y += alpha * x` over the common length of `x` and `y`
+/// 
+/// # Safety
+/// The CPU must support AVX2 and FMA
+#[target_feature(enable = "avx2,fma")]
+pub fn axpy(alpha: Scalar, x: &[Scalar], y: &mut [Scalar]) {
+  let n = x.len().min(y.len());
+  let (x, y) = (x.as_ptr(), y.as_mut_ptr());
+  let alpha_v = _mm256_set1_pd(alpha);
+
+  let mut i = 0;
+  while i + LANES <= n {
+    // Safety: i + LANES <= n for both slices
+    unsafe {
+      let res = _mm256_fmadd_pd(alpha_v, _mm256_loadu_pd(x.add(i)), _mm256_loadu_pd(y.add(i)));
+      _mm256_storeu_pd(y.add(i), res);
+    }
+    i += LANES;
+  }
+  for j in i..n {
+    // Safety: j < n
+    unsafe { *y.add(j) += alpha * *x.add(j) };
+  }
+}
+
+/// Blocked `out += a * b`, see `kernels::gemm`
+/// 
+/// # Safety
+/// The CPU must support AVX2 and FMA
+#[target_feature(enable = "avx2,fma")]
+pub fn gemm(a: &[Scalar], b: &[Scalar], out: &mut [Scalar], m: usize, k: usize, n: usize) {
+  super::blocked_gemm(a, b, out, m, k, n, |alpha, x, y| axpy(alpha, x, y));
+}
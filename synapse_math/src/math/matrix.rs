@@ -4,69 +4,143 @@
 /// and exposes important functionalities for
 /// the data type
 /// 
+/// The fields are private so `data.len() == rows * cols`
+/// always holds, matrices are built through the checked
+/// constructors and only the methods in this file change
+/// the shape of an existing Matrix
+/// 
 /// Author: Fedi Nabli
 /// Date: 19 May 2025
-/// Last Modified: 23 May 2025
+/// Last Modified: 17 October 2026
 
-use super::{scalar, Scalar, Vector};
+use super::{kernels, Float, MatrixView, Scalar, Vector, VectorView};
 
-use crate::error::Error;
+use crate::error::{Error, MatrixIndex};
 
+/// Row-major matrix, see `from_vec`, `from_rows`, `from_fn` and `diag`
 #[derive(Debug, Clone)]
-pub struct Matrix {
-  pub rows: usize,
-  pub cols: usize,
-  pub data: Vec<Scalar>
+pub struct Matrix<T = Scalar> {
+  rows: usize,
+  cols: usize,
+  data: Vec<T>
+}
+
+/// Number of entries of a (rows, cols) matrix, failing instead of overflowing
+fn checked_len(rows: usize, cols: usize) -> Result<usize, Error> {
+  rows.checked_mul(cols).ok_or_else(|| Error::InvalidInput(format!(
+    "{}x{} matrix has more entries than fit in memory", rows, cols
+  )))
+}
+
+impl<T> Matrix<T> {
+  pub fn rows(&self) -> usize {
+    self.rows
+  }
+
+  pub fn cols(&self) -> usize {
+    self.cols
+  }
+
+  pub fn shape(&self) -> (usize, usize) {
+    (self.rows, self.cols)
+  }
+
+  /// Row-major entries
+  pub fn data(&self) -> &[T] {
+    &self.data
+  }
+
+  /// Row-major entries, mutable in place without changing the shape
+  pub fn data_mut(&mut self) -> &mut [T] {
+    &mut self.data
+  }
+
+  /// Take back the row-major entries
+  pub fn into_vec(self) -> Vec<T> {
+    self.data
+  }
 }
 
-impl Matrix {
-  pub fn zeros(rows: usize, cols: usize) -> Matrix {
+impl<T: Float> Matrix<T> {
+  /// Build a (rows, cols) matrix from its row-major entries
+  pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Matrix<T>, Error> {
+    if checked_len(rows, cols)? != data.len() {
+      return Err(Error::MatDimensionMismatch { left: (rows, cols), right: (1, data.len()) });
+    }
+
+    Ok(Matrix { rows, cols, data })
+  }
+
+  /// Build a matrix from rows of the same length
+  pub fn from_rows<R: AsRef<[T]>>(rows: &[R]) -> Result<Matrix<T>, Error> {
+    let cols = rows.first().map_or(0, |row| row.as_ref().len());
+    if let Some(row) = rows.iter().find(|row| row.as_ref().len() != cols) {
+      return Err(Error::MatDimensionMismatch { left: (1, cols), right: (1, row.as_ref().len()) });
+    }
+
+    let data = rows.iter().flat_map(|row| row.as_ref().iter().copied()).collect();
+    Ok(Matrix { rows: rows.len(), cols, data })
+  }
+
+  /// Build a (rows, cols) matrix whose entry (i, j) is `f(i, j)`
+  pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Result<Matrix<T>, Error> {
+    let data = (0..checked_len(rows, cols)?).map(|idx| f(idx / cols, idx % cols)).collect();
+    Ok(Matrix { rows, cols, data })
+  }
+
+  /// Square matrix with `values` on the diagonal and zeros elsewhere
+  pub fn diag<'v>(values: impl Into<VectorView<'v, T>>) -> Result<Matrix<T>, Error> {
+    let values = values.into();
+    Matrix::from_fn(values.len(), values.len(), |i, j| if i == j { values.data[i] } else { T::ZERO })
+  }
+
+  /// Wrap `data` the caller sized to `rows * cols` entries itself,
+  /// used by the math module when building its results
+  pub(crate) fn from_parts(rows: usize, cols: usize, data: Vec<T>) -> Matrix<T> {
+    debug_assert_eq!(data.len(), rows * cols);
+    Matrix { rows, cols, data }
+  }
+
+  pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
     Matrix {
       cols,
       rows,
-      data: vec![scalar::zero(); rows * cols]
+      data: vec![T::ZERO; rows * cols]
     }
   }
 
-  pub fn ones(rows: usize, cols: usize) -> Matrix {
+  pub fn ones(rows: usize, cols: usize) -> Matrix<T> {
     Matrix {
       cols,
       rows,
-      data: vec![scalar::one(); rows * cols]
+      data: vec![T::ONE; rows * cols]
     }
   }
 
-  pub fn identity(len: usize) -> Matrix {
+  pub fn identity(len: usize) -> Matrix<T> {
     let mut mat = Matrix::zeros(len, len);
     for i in 0..len {
       for j in 0..len {
         if i == j {
-          mat.data[i * len + j] = scalar::one();
+          mat.data[i * len + j] = T::ONE;
         }
       }
     }
 
-    return mat;
-  }
-
-  pub fn shape(&self) -> (usize, usize) {
-    return (self.rows, self.cols);
+    mat
   }
 
-  pub fn get(&self, row: usize, col: usize) -> Option<Scalar> {
+  pub fn get(&self, row: usize, col: usize) -> Option<T> {
     if row >= self.rows || col >= self.cols {
       return None;
     }
 
-    match self.data.get(row * self.cols + col) {
-      Some(&value) => Some(value),
-      None => None,
-    }
+    self.data.get(row * self.cols + col).copied()
   }
 
-  pub fn set(&mut self, row: usize, col: usize, s: Scalar) -> Result<(), Error> {
+  pub fn set(&mut self, row: usize, col: usize, s: T) -> Result<(), Error> {
     if row >= self.rows || col >= self.cols {
-      return Err(Error::MatrixIndexOutOfBounds);
+      return Err(Error::MatrixIndexOutOfBounds { index: MatrixIndex::Entry(row, col), shape: self.shape() });
     }
 
     self.data[row * self.cols + col] = s;
@@ -74,83 +148,126 @@ impl Matrix {
     Ok(())
   }
 
-  pub fn transpose(&self) -> Matrix {
-    let mut trans_mat = Matrix::zeros(self.cols, self.rows);
-
-    for i in 0..self.rows {
-      for j in 0..self.cols {
-        trans_mat.data[j * self.rows + i] = self.data[i * self.cols + j];
-      }
-    }
-
-    trans_mat
+  pub fn transpose(&self) -> Matrix<T> {
+    self.view().transpose()
   }
 
-  pub fn mat_mul(&self, other: &Matrix) -> Result<Matrix, Error> {
+  pub fn mat_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
     if self.cols != other.rows {
-      return Err(Error::MatDimensionMismatch);
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: other.shape() });
     }
 
     let mut new_mat = Matrix::zeros(self.rows, other.cols);
-
-    for i in 0..self.rows {
-      for j in 0..other.cols {
-        let mut sum = scalar::zero();
-        for k in 0..self.cols {
-          sum += self.data[i * self.cols + k] * other.data[k * other.cols + j];
-        }
-        new_mat.data[i * other.cols + j] = sum;
-      }
-    }
+    kernels::gemm(&self.data, &other.data, &mut new_mat.data, self.rows, self.cols, other.cols);
 
     Ok(new_mat)
   }
 
-  pub fn vec_mul(&self, vec: &Vector) -> Result<Vector, Error> {
-    if self.cols != vec.len() {
-      return Err(Error::MatDimensionMismatch);
-    }
+  pub fn vec_mul<'v>(&self, vec: impl Into<VectorView<'v, T>>) -> Result<Vector<T>, Error> {
+    self.view().vec_mul(vec.into())
+  }
+
+  /// Apply `f` to every entry
+  pub fn map(&self, f: impl FnMut(T) -> T) -> Matrix<T> {
+    Matrix { rows: self.rows, cols: self.cols, data: self.data.iter().copied().map(f).collect() }
+  }
 
-    let mut res_mat = Vector::zeroes(self.rows);
+  /// Apply `f` to every entry in place
+  pub fn map_inplace(&mut self, mut f: impl FnMut(T) -> T) {
+    self.data.iter_mut().for_each(|a| *a = f(*a));
+  }
 
-    for i in 0..self.rows {
-      let mut sum = scalar::zero();
-      for k in 0..self.cols {
-        sum += self.data[i * self.cols + k] * vec.get(k).unwrap();
-      }
-      res_mat.set(i, sum).unwrap();
+  /// Combine the matching entries of `self` and `other` with `f`
+  pub fn zip_map(&self, other: &Matrix<T>, mut f: impl FnMut(T, T) -> T) -> Result<Matrix<T>, Error> {
+    if self.shape() != other.shape() {
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: other.shape() });
     }
 
-    Ok(res_mat)
+    let data = self.data.iter().zip(&other.data).map(|(&a, &b)| f(a, b)).collect();
+    Ok(Matrix { rows: self.rows, cols: self.cols, data })
   }
 
-  pub fn add(&self, other: &Matrix) -> Result<Matrix, Error> {
+  /// Checked `self + other`, see `math::ops` for the operator form
+  pub fn add(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
     if self.rows != other.rows || self.cols != other.cols {
-      return Err(Error::MatDimensionMismatch);
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: other.shape() });
     }
 
     let mut res_mat = Matrix::zeros(self.rows, self.cols);
 
     res_mat.data = self.data.iter()
       .zip(&other.data)
-      .map(|(&a, &b)| scalar::add(a, b))
-      .collect::<Vec<Scalar>>();
+      .map(|(&a, &b)| a + b)
+      .collect::<Vec<T>>();
 
     Ok(res_mat)
   }
 
-  pub fn sub(&self, other: &Matrix) -> Result<Matrix, Error> {
+  /// Checked `self - other`, see `math::ops` for the operator form
+  pub fn sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
     if self.rows != other.rows || self.cols != other.cols {
-      return Err(Error::MatDimensionMismatch);
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: other.shape() });
     }
 
     let mut res_mat = Matrix::zeros(self.rows, self.cols);
 
     res_mat.data = self.data.iter()
       .zip(&other.data)
-      .map(|(&a, &b)| scalar::sub(a, b))
-      .collect::<Vec<Scalar>>();
+      .map(|(&a, &b)| a - b)
+      .collect::<Vec<T>>();
 
     Ok(res_mat)
   }
+
+  /// Same as `add`, named after the `+` operator it checks
+  pub fn try_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
+    self.add(other)
+  }
+
+  /// Same as `sub`, named after the `-` operator it checks
+  pub fn try_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
+    self.sub(other)
+  }
+
+  /// Append `row` at the bottom
+  pub fn push_row<'v>(&mut self, row: impl Into<VectorView<'v, T>>) -> Result<(), Error> {
+    let row = row.into();
+    if row.len() != self.cols {
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: (1, row.len()) });
+    }
+
+    self.data.extend_from_slice(row.data);
+    self.rows += 1;
+    Ok(())
+  }
+
+  /// Append `col` on the right
+  pub fn push_col<'v>(&mut self, col: impl Into<VectorView<'v, T>>) -> Result<(), Error> {
+    let col = col.into();
+    if col.len() != self.rows {
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: (col.len(), 1) });
+    }
+
+    *self = Matrix::hstack(&[self.view(), MatrixView::new(self.rows, 1, col.data)?])?;
+    Ok(())
+  }
+
+  /// Remove column `col` and return it
+  pub fn remove_col(&mut self, col: usize) -> Result<Vector<T>, Error> {
+    let removed = self.column(col).ok_or(Error::MatrixIndexOutOfBounds {
+      index: MatrixIndex::Column(col),
+      shape: self.shape(),
+    })?;
+
+    let cols = self.cols;
+    let mut idx = 0;
+    self.data.retain(|_| {
+      let keep = idx % cols != col;
+      idx += 1;
+      keep
+    });
+    self.cols -= 1;
+
+    Ok(removed)
+  }
 }
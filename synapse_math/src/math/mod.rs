@@ -5,16 +5,24 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 19 May 2025
-/// Last Modified: 19 May 2025
+/// Last Modified: 17 October 2026
 
 
 pub mod scalar;
 pub mod vector;
 pub mod matrix;
 pub mod elem;
+pub mod view;
+pub mod ops;
+pub mod kernels;
+pub mod decomp;
+pub mod slice;
+pub mod reduce;
 
-pub use scalar::Scalar;
+pub use scalar::{Float, Scalar};
 pub use vector::Vector;
 pub use matrix::Matrix;
+pub use view::{VectorView, MatrixView};
+pub use reduce::Axis;
 
 pub use elem::{exp, ln, sqrt, abs, cos, sin, tan, cosh, sinh, tanh};
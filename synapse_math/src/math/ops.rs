@@ -0,0 +1,304 @@
+/// math/ops.rs - Math Engine arithmetic operators
+/// 
+/// This file implements the std::ops operators for
+/// Vector and Matrix
+/// 
+/// Operators panic when the shapes do not match, the same
+/// way slice indexing panics out of bounds. Use the checked
+/// `add` / `sub` methods (or their `try_add` / `try_sub` aliases)
+/// when shapes come from user input and the mismatch has to be
+/// reported as an Error
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, Index, IndexMut};
+
+use super::{Float, Matrix, Vector};
+
+#[track_caller]
+fn assert_same_len<T: Float>(op: &str, lhs: &Vector<T>, rhs: &Vector<T>) {
+  assert!(
+    lhs.len() == rhs.len(),
+    "vector dimension mismatch in `{}`: {} vs {}", op, lhs.len(), rhs.len(),
+  );
+}
+
+#[track_caller]
+fn assert_same_shape<T: Float>(op: &str, lhs: &Matrix<T>, rhs: &Matrix<T>) {
+  assert!(
+    lhs.shape() == rhs.shape(),
+    "matrix dimension mismatch in `{}`: {}x{} vs {}x{}", op, lhs.rows(), lhs.cols(), rhs.rows(), rhs.cols(),
+  );
+}
+
+/// Flat entries of both containers, Matrix keeps its own private
+trait Entries<T> {
+  fn entries(&self) -> &[T];
+  fn entries_mut(&mut self) -> &mut [T];
+}
+
+impl<T: Float> Entries<T> for Vector<T> {
+  fn entries(&self) -> &[T] {
+    &self.data
+  }
+
+  fn entries_mut(&mut self) -> &mut [T] {
+    &mut self.data
+  }
+}
+
+impl<T: Float> Entries<T> for Matrix<T> {
+  fn entries(&self) -> &[T] {
+    self.data()
+  }
+
+  fn entries_mut(&mut self) -> &mut [T] {
+    self.data_mut()
+  }
+}
+
+/// Element wise `lhs op= rhs` for both containers, every other
+/// form of the operator is derived from it
+macro_rules! elementwise_ops {
+  ($ty:ident, $check:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt, $name:literal) => {
+    impl<T: Float> $assign_trait<&$ty<T>> for $ty<T> {
+      #[track_caller]
+      fn $assign_method(&mut self, rhs: &$ty<T>) {
+        $check($name, self, rhs);
+        for (a, &b) in self.entries_mut().iter_mut().zip(rhs.entries()) {
+          *a $op b;
+        }
+      }
+    }
+
+    impl<T: Float> $assign_trait<$ty<T>> for $ty<T> {
+      #[track_caller]
+      fn $assign_method(&mut self, rhs: $ty<T>) {
+        *self $op &rhs;
+      }
+    }
+
+    impl<T: Float> $trait<&$ty<T>> for $ty<T> {
+      type Output = $ty<T>;
+
+      #[track_caller]
+      fn $method(mut self, rhs: &$ty<T>) -> $ty<T> {
+        self $op rhs;
+        self
+      }
+    }
+
+    impl<T: Float> $trait<$ty<T>> for $ty<T> {
+      type Output = $ty<T>;
+
+      #[track_caller]
+      fn $method(mut self, rhs: $ty<T>) -> $ty<T> {
+        self $op &rhs;
+        self
+      }
+    }
+
+    impl<T: Float> $trait<&$ty<T>> for &$ty<T> {
+      type Output = $ty<T>;
+
+      #[track_caller]
+      fn $method(self, rhs: &$ty<T>) -> $ty<T> {
+        let mut out = self.clone();
+        out $op rhs;
+        out
+      }
+    }
+  };
+}
+
+/// T arithmetic applied to every entry and negation
+/// for both containers
+macro_rules! scalar_ops {
+  ($ty:ident) => {
+    impl<T: Float> AddAssign<T> for $ty<T> {
+      fn add_assign(&mut self, rhs: T) {
+        self.entries_mut().iter_mut().for_each(|a| *a += rhs);
+      }
+    }
+
+    impl<T: Float> SubAssign<T> for $ty<T> {
+      fn sub_assign(&mut self, rhs: T) {
+        self.entries_mut().iter_mut().for_each(|a| *a -= rhs);
+      }
+    }
+
+    impl<T: Float> MulAssign<T> for $ty<T> {
+      fn mul_assign(&mut self, rhs: T) {
+        self.entries_mut().iter_mut().for_each(|a| *a *= rhs);
+      }
+    }
+
+    impl<T: Float> DivAssign<T> for $ty<T> {
+      fn div_assign(&mut self, rhs: T) {
+        self.entries_mut().iter_mut().for_each(|a| *a /= rhs);
+      }
+    }
+
+    impl<T: Float> Add<T> for $ty<T> {
+      type Output = $ty<T>;
+
+      fn add(mut self, rhs: T) -> $ty<T> {
+        self += rhs;
+        self
+      }
+    }
+
+    impl<T: Float> Add<T> for &$ty<T> {
+      type Output = $ty<T>;
+
+      fn add(self, rhs: T) -> $ty<T> {
+        self.clone() + rhs
+      }
+    }
+
+    impl<T: Float> Sub<T> for $ty<T> {
+      type Output = $ty<T>;
+
+      fn sub(mut self, rhs: T) -> $ty<T> {
+        self -= rhs;
+        self
+      }
+    }
+
+    impl<T: Float> Sub<T> for &$ty<T> {
+      type Output = $ty<T>;
+
+      fn sub(self, rhs: T) -> $ty<T> {
+        self.clone() - rhs
+      }
+    }
+
+    impl<T: Float> Mul<T> for $ty<T> {
+      type Output = $ty<T>;
+
+      fn mul(mut self, rhs: T) -> $ty<T> {
+        self *= rhs;
+        self
+      }
+    }
+
+    impl<T: Float> Mul<T> for &$ty<T> {
+      type Output = $ty<T>;
+
+      fn mul(self, rhs: T) -> $ty<T> {
+        self.clone() * rhs
+      }
+    }
+
+    impl<T: Float> Div<T> for $ty<T> {
+      type Output = $ty<T>;
+
+      fn div(mut self, rhs: T) -> $ty<T> {
+        self /= rhs;
+        self
+      }
+    }
+
+    impl<T: Float> Div<T> for &$ty<T> {
+      type Output = $ty<T>;
+
+      fn div(self, rhs: T) -> $ty<T> {
+        self.clone() / rhs
+      }
+    }
+
+    impl<T: Float> Neg for $ty<T> {
+      type Output = $ty<T>;
+
+      fn neg(mut self) -> $ty<T> {
+        self.entries_mut().iter_mut().for_each(|a| *a = -*a);
+        self
+      }
+    }
+
+    impl<T: Float> Neg for &$ty<T> {
+      type Output = $ty<T>;
+
+      fn neg(self) -> $ty<T> {
+        -self.clone()
+      }
+    }
+  };
+}
+
+/// `Scalar * container` has to name the element type, the
+/// orphan rule rejects a blanket `impl<T> Mul<Vector<T>> for T`
+macro_rules! scalar_lhs_ops {
+  ($ty:ident, $elem:ty) => {
+    impl Mul<$ty<$elem>> for $elem {
+      type Output = $ty<$elem>;
+
+      fn mul(self, rhs: $ty<$elem>) -> $ty<$elem> {
+        rhs * self
+      }
+    }
+
+    impl Mul<&$ty<$elem>> for $elem {
+      type Output = $ty<$elem>;
+
+      fn mul(self, rhs: &$ty<$elem>) -> $ty<$elem> {
+        rhs * self
+      }
+    }
+  };
+}
+
+elementwise_ops!(Vector, assert_same_len, Add, add, AddAssign, add_assign, +=, "+");
+elementwise_ops!(Vector, assert_same_len, Sub, sub, SubAssign, sub_assign, -=, "-");
+elementwise_ops!(Matrix, assert_same_shape, Add, add, AddAssign, add_assign, +=, "+");
+elementwise_ops!(Matrix, assert_same_shape, Sub, sub, SubAssign, sub_assign, -=, "-");
+scalar_ops!(Vector);
+scalar_ops!(Matrix);
+scalar_lhs_ops!(Vector, f64);
+scalar_lhs_ops!(Vector, f32);
+scalar_lhs_ops!(Matrix, f64);
+scalar_lhs_ops!(Matrix, f32);
+
+impl<T: Float> Index<usize> for Vector<T> {
+  type Output = T;
+
+  #[track_caller]
+  fn index(&self, idx: usize) -> &T {
+    &self.data[idx]
+  }
+}
+
+impl<T: Float> IndexMut<usize> for Vector<T> {
+  #[track_caller]
+  fn index_mut(&mut self, idx: usize) -> &mut T {
+    &mut self.data[idx]
+  }
+}
+
+#[track_caller]
+fn flat_index<T>(mat: &Matrix<T>, (row, col): (usize, usize)) -> usize {
+  assert!(
+    row < mat.rows() && col < mat.cols(),
+    "matrix index ({}, {}) out of bounds for {}x{} matrix", row, col, mat.rows(), mat.cols(),
+  );
+  row * mat.cols() + col
+}
+
+impl<T: Float> Index<(usize, usize)> for Matrix<T> {
+  type Output = T;
+
+  #[track_caller]
+  fn index(&self, idx: (usize, usize)) -> &T {
+    &self.data()[flat_index(self, idx)]
+  }
+}
+
+impl<T: Float> IndexMut<(usize, usize)> for Matrix<T> {
+  #[track_caller]
+  fn index_mut(&mut self, idx: (usize, usize)) -> &mut T {
+    let idx = flat_index(self, idx);
+    &mut self.data_mut()[idx]
+  }
+}
@@ -0,0 +1,218 @@
+/// math/reduce.rs - Math Engine axis reductions and broadcasting
+/// 
+/// This file implements per row and per column reductions
+/// (sum, mean, min, max, argmin, argmax) and the broadcasting
+/// of a Vector across every row or column of a Matrix
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+
+use super::{Float, Matrix, MatrixView, Vector, VectorView};
+
+/// The index a per axis Vector follows
+/// 
+/// Reducing along `Row` gives one value per row, and broadcasting
+/// along `Row` expects one value per row, so a reduction can be
+/// broadcast straight back onto the matrix it came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+  Row,
+  Column,
+}
+
+impl<T: Float> MatrixView<'_, T> {
+  /// (number of outputs, number of entries reduced into each)
+  fn axis_dims(&self, axis: Axis) -> (usize, usize) {
+    match axis {
+      Axis::Row => (self.rows(), self.cols()),
+      Axis::Column => (self.cols(), self.rows()),
+    }
+  }
+
+  /// Index and value of the entry preferred by `better` on every
+  /// row or column, the first one wins ties
+  fn arg_reduce(
+    &self,
+    axis: Axis,
+    better: fn(T, T) -> bool,
+  ) -> Result<Vec<(usize, T)>, Error> {
+    let (outputs, reduced) = self.axis_dims(axis);
+    if outputs > 0 && reduced == 0 {
+      return Err(Error::InsufficientData);
+    }
+
+    let pick = |best: &mut (usize, T), idx: usize, val: T| {
+      if better(val, best.1) {
+        *best = (idx, val);
+      }
+    };
+
+    let out = match axis {
+      Axis::Row => self.row_iter()
+        .map(|row| {
+          let mut best = (0, row[0]);
+          row.iter().enumerate().skip(1).for_each(|(col, &val)| pick(&mut best, col, val));
+          best
+        })
+        .collect(),
+      Axis::Column => {
+        let mut rows = self.row_iter();
+        let mut best: Vec<_> = rows.next().unwrap_or_default().iter().map(|&val| (0, val)).collect();
+        for (row_idx, row) in rows.enumerate() {
+          best.iter_mut().zip(row).for_each(|(b, &val)| pick(b, row_idx + 1, val));
+        }
+        best
+      }
+    };
+
+    Ok(out)
+  }
+
+  /// Sum of every row or column
+  pub fn sum_axis(&self, axis: Axis) -> Vector<T> {
+    let data = match axis {
+      Axis::Row => self.row_iter().map(|row| row.iter().copied().sum()).collect(),
+      Axis::Column => {
+        let mut sums = vec![T::ZERO; self.cols()];
+        for row in self.row_iter() {
+          sums.iter_mut().zip(row).for_each(|(s, &val)| *s += val);
+        }
+        sums
+      }
+    };
+
+    Vector { data }
+  }
+
+  /// Mean of every row or column
+  pub fn mean_axis(&self, axis: Axis) -> Result<Vector<T>, Error> {
+    let (outputs, reduced) = self.axis_dims(axis);
+    if outputs > 0 && reduced == 0 {
+      return Err(Error::InsufficientData);
+    }
+
+    Ok(self.sum_axis(axis) / T::from_usize(reduced))
+  }
+
+  /// Smallest entry of every row or column
+  pub fn min_axis(&self, axis: Axis) -> Result<Vector<T>, Error> {
+    let best = self.arg_reduce(axis, |val, best| val < best)?;
+    Ok(Vector { data: best.into_iter().map(|(_, val)| val).collect() })
+  }
+
+  /// Largest entry of every row or column
+  pub fn max_axis(&self, axis: Axis) -> Result<Vector<T>, Error> {
+    let best = self.arg_reduce(axis, |val, best| val > best)?;
+    Ok(Vector { data: best.into_iter().map(|(_, val)| val).collect() })
+  }
+
+  /// Position of the smallest entry of every row or column
+  pub fn argmin_axis(&self, axis: Axis) -> Result<Vec<usize>, Error> {
+    let best = self.arg_reduce(axis, |val, best| val < best)?;
+    Ok(best.into_iter().map(|(idx, _)| idx).collect())
+  }
+
+  /// Position of the largest entry of every row or column
+  pub fn argmax_axis(&self, axis: Axis) -> Result<Vec<usize>, Error> {
+    let best = self.arg_reduce(axis, |val, best| val > best)?;
+    Ok(best.into_iter().map(|(idx, _)| idx).collect())
+  }
+
+  /// Combine every entry with the matching entry of `vec`,
+  /// which follows `axis` (one value per row or per column)
+  fn broadcast(
+    &self,
+    vec: VectorView<T>,
+    axis: Axis,
+    op: fn(T, T) -> T,
+  ) -> Result<Matrix<T>, Error> {
+    if vec.len() != self.axis_dims(axis).0 {
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: (vec.len(), 1) });
+    }
+
+    let mut data = Vec::with_capacity(self.data().len());
+    for (row_idx, row) in self.row_iter().enumerate() {
+      match axis {
+        Axis::Row => data.extend(row.iter().map(|&a| op(a, vec.data[row_idx]))),
+        Axis::Column => data.extend(row.iter().zip(vec.data).map(|(&a, &b)| op(a, b))),
+      }
+    }
+
+    Ok(Matrix::from_parts(self.rows(), self.cols(), data))
+  }
+
+  /// Add `vec` to every column (`Axis::Row`) or every row (`Axis::Column`)
+  pub fn broadcast_add<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.broadcast(vec.into(), axis, |a, b| a + b)
+  }
+
+  /// Subtract `vec` from every column (`Axis::Row`) or every row (`Axis::Column`)
+  pub fn broadcast_sub<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.broadcast(vec.into(), axis, |a, b| a - b)
+  }
+
+  /// Multiply every column (`Axis::Row`) or every row (`Axis::Column`) by `vec`
+  pub fn broadcast_mul<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.broadcast(vec.into(), axis, |a, b| a * b)
+  }
+
+  /// Divide every column (`Axis::Row`) or every row (`Axis::Column`) by `vec`
+  pub fn broadcast_div<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.broadcast(vec.into(), axis, |a, b| a / b)
+  }
+}
+
+impl<T: Float> Matrix<T> {
+  /// Sum of every row or column
+  pub fn sum_axis(&self, axis: Axis) -> Vector<T> {
+    self.view().sum_axis(axis)
+  }
+
+  /// Mean of every row or column
+  pub fn mean_axis(&self, axis: Axis) -> Result<Vector<T>, Error> {
+    self.view().mean_axis(axis)
+  }
+
+  /// Smallest entry of every row or column
+  pub fn min_axis(&self, axis: Axis) -> Result<Vector<T>, Error> {
+    self.view().min_axis(axis)
+  }
+
+  /// Largest entry of every row or column
+  pub fn max_axis(&self, axis: Axis) -> Result<Vector<T>, Error> {
+    self.view().max_axis(axis)
+  }
+
+  /// Position of the smallest entry of every row or column
+  pub fn argmin_axis(&self, axis: Axis) -> Result<Vec<usize>, Error> {
+    self.view().argmin_axis(axis)
+  }
+
+  /// Position of the largest entry of every row or column
+  pub fn argmax_axis(&self, axis: Axis) -> Result<Vec<usize>, Error> {
+    self.view().argmax_axis(axis)
+  }
+
+  /// Add `vec` to every column (`Axis::Row`) or every row (`Axis::Column`)
+  pub fn broadcast_add<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.view().broadcast_add(vec, axis)
+  }
+
+  /// Subtract `vec` from every column (`Axis::Row`) or every row (`Axis::Column`)
+  pub fn broadcast_sub<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.view().broadcast_sub(vec, axis)
+  }
+
+  /// Multiply every column (`Axis::Row`) or every row (`Axis::Column`) by `vec`
+  pub fn broadcast_mul<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.view().broadcast_mul(vec, axis)
+  }
+
+  /// Divide every column (`Axis::Row`) or every row (`Axis::Column`) by `vec`
+  pub fn broadcast_div<'v>(&self, vec: impl Into<VectorView<'v, T>>, axis: Axis) -> Result<Matrix<T>, Error> {
+    self.view().broadcast_div(vec, axis)
+  }
+}
@@ -1,14 +1,125 @@
 /// math/scalar.rs - Math Engine Scalar type
 /// 
 /// Scalar is just a wrapper around f64 with helper
-/// functions for the type
+/// functions for the type. It is the default element of
+/// Vector and Matrix, which accept any `Float` (f64 or f32)
+/// 
+/// The `T = Scalar` default of the containers only applies
+/// where their type is written out (`let v: Vector = ...`),
+/// it doesn't drive inference so `Vector::zeroes(3)` alone
+/// needs `Vector::<f64>::zeroes(3)` or an annotation
 /// 
 /// Author: Fedi Nabli
 /// Date: 19 May 2025
-/// Last Modified: 19 May 2025
+/// Last Modified: 17 October 2026
+
+use std::borrow::Cow;
+
+use core::fmt::{Debug, Display};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+use super::kernels::Kernel;
 
 pub type Scalar = f64;
 
+/// Floating point element of Vector, Matrix, losses, activations and
+/// optimizers, implemented for f64 (the default) and f32
+pub trait Float:
+  Copy + Debug + Display + Default + PartialEq + PartialOrd + Send + Sync + 'static
+  + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+  + AddAssign + SubAssign + MulAssign + DivAssign + Sum + for<'a> Sum<&'a Self>
+  + Kernel
+{
+  const ZERO: Self;
+  const ONE: Self;
+  /// Machine epsilon
+  const EPSILON: Self;
+
+  /// Nearest value of `x`, rounding when narrowing to f32
+  fn from_f64(x: f64) -> Self;
+  fn to_f64(self) -> f64;
+  fn from_usize(n: usize) -> Self;
+  /// Borrow f64 data as `Self`, only copying it when narrowing
+  fn from_f64_slice(data: &[f64]) -> Cow<'_, [Self]>;
+  /// Take `data` as f64, only converting it when widening
+  fn into_f64_vec(data: Vec<Self>) -> Vec<f64>;
+
+  fn exp(self) -> Self;
+  fn ln(self) -> Self;
+  fn sqrt(self) -> Self;
+  fn abs(self) -> Self;
+  fn cos(self) -> Self;
+  fn sin(self) -> Self;
+  fn tan(self) -> Self;
+  fn cosh(self) -> Self;
+  fn sinh(self) -> Self;
+  fn tanh(self) -> Self;
+  fn powi(self, n: i32) -> Self;
+  fn powf(self, n: Self) -> Self;
+  fn max(self, other: Self) -> Self;
+  fn min(self, other: Self) -> Self;
+  fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_float {
+  ($ty:ident, |$slice:ident| $from_slice:expr, |$vec:ident| $into_vec:expr) => {
+    impl Float for $ty {
+      const ZERO: Self = 0.0;
+      const ONE: Self = 1.0;
+      const EPSILON: Self = $ty::EPSILON;
+
+      #[inline]
+      fn from_f64(x: f64) -> Self { x as $ty }
+      #[inline]
+      fn to_f64(self) -> f64 { self as f64 }
+      #[inline]
+      fn from_usize(n: usize) -> Self { n as $ty }
+      #[inline]
+      fn from_f64_slice($slice: &[f64]) -> Cow<'_, [Self]> { $from_slice }
+      #[inline]
+      fn into_f64_vec($vec: Vec<Self>) -> Vec<f64> { $into_vec }
+
+      #[inline]
+      fn exp(self) -> Self { $ty::exp(self) }
+      #[inline]
+      fn ln(self) -> Self { $ty::ln(self) }
+      #[inline]
+      fn sqrt(self) -> Self { $ty::sqrt(self) }
+      #[inline]
+      fn abs(self) -> Self { $ty::abs(self) }
+      #[inline]
+      fn cos(self) -> Self { $ty::cos(self) }
+      #[inline]
+      fn sin(self) -> Self { $ty::sin(self) }
+      #[inline]
+      fn tan(self) -> Self { $ty::tan(self) }
+      #[inline]
+      fn cosh(self) -> Self { $ty::cosh(self) }
+      #[inline]
+      fn sinh(self) -> Self { $ty::sinh(self) }
+      #[inline]
+      fn tanh(self) -> Self { $ty::tanh(self) }
+      #[inline]
+      fn powi(self, n: i32) -> Self { $ty::powi(self, n) }
+      #[inline]
+      fn powf(self, n: Self) -> Self { $ty::powf(self, n) }
+      #[inline]
+      fn max(self, other: Self) -> Self { $ty::max(self, other) }
+      #[inline]
+      fn min(self, other: Self) -> Self { $ty::min(self, other) }
+      #[inline]
+      fn is_finite(self) -> bool { $ty::is_finite(self) }
+    }
+  };
+}
+
+impl_float!(f64, |data| Cow::Borrowed(data), |data| data);
+impl_float!(f32,
+  |data| Cow::Owned(data.iter().map(|&x| x as f32).collect()),
+  |data| data.into_iter().map(f64::from).collect()
+);
+
 #[inline]
 pub const fn zero() -> Scalar { 0.0 }
 
@@ -0,0 +1,198 @@
+/// math/slice.rs - Math Engine slicing and stacking
+/// 
+/// This file implements row and column access, submatrices,
+/// row/column selection, stacking and column drop/append for
+/// Matrix so callers don't index the row-major `data` by hand
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::ops::Range;
+
+use crate::error::{Error, MatrixIndex};
+
+use super::{Float, Matrix, MatrixView, Vector, VectorView};
+
+impl<'a, T: Float> MatrixView<'a, T> {
+  /// Iterate over the rows as slices
+  pub fn row_iter(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+    let (cols, data) = (self.cols(), self.data());
+    (0..self.rows()).map(move |row| &data[row * cols..(row + 1) * cols])
+  }
+
+  /// Iterate over the entries of column `col`, top to bottom
+  pub fn column_iter(&self, col: usize) -> Option<impl Iterator<Item = T> + use<'a, T>> {
+    if col >= self.cols() {
+      return None;
+    }
+
+    Some(self.data().iter().skip(col).step_by(self.cols()).copied())
+  }
+
+  /// Copy column `col` into a Vector
+  pub fn column(&self, col: usize) -> Option<Vector<T>> {
+    Some(Vector { data: self.column_iter(col)?.collect() })
+  }
+
+  /// Borrow the contiguous rows in `rows` without copying
+  pub fn slice_rows(&self, rows: Range<usize>) -> Result<MatrixView<'a, T>, Error> {
+    if rows.start > rows.end {
+      return Err(Error::InvalidParameter(format!("row range {:?} is reversed", rows)));
+    }
+    if rows.end > self.rows() {
+      return Err(Error::MatrixIndexOutOfBounds { index: MatrixIndex::Row(rows.end - 1), shape: self.shape() });
+    }
+
+    MatrixView::new(rows.len(), self.cols(), &self.data()[rows.start * self.cols()..rows.end * self.cols()])
+  }
+
+  /// Copy the block of `rows` x `cols`
+  pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>, Error> {
+    if cols.start > cols.end {
+      return Err(Error::InvalidParameter(format!("column range {:?} is reversed", cols)));
+    }
+    if cols.end > self.cols() {
+      return Err(Error::MatrixIndexOutOfBounds { index: MatrixIndex::Column(cols.end - 1), shape: self.shape() });
+    }
+
+    let block = self.slice_rows(rows)?;
+    let mut data = Vec::with_capacity(block.rows() * cols.len());
+    for row in block.row_iter() {
+      data.extend_from_slice(&row[cols.clone()]);
+    }
+
+    Ok(Matrix::from_parts(block.rows(), cols.len(), data))
+  }
+
+  /// Copy the rows listed in `indices`, in that order (repeats allowed)
+  pub fn select_rows(&self, indices: &[usize]) -> Result<Matrix<T>, Error> {
+    let mut data = Vec::with_capacity(indices.len() * self.cols());
+    for &idx in indices {
+      data.extend_from_slice(self.row(idx).ok_or(Error::MatrixIndexOutOfBounds {
+        index: MatrixIndex::Row(idx),
+        shape: self.shape(),
+      })?);
+    }
+
+    Ok(Matrix::from_parts(indices.len(), self.cols(), data))
+  }
+
+  /// Copy the columns listed in `indices`, in that order (repeats allowed)
+  pub fn select_cols(&self, indices: &[usize]) -> Result<Matrix<T>, Error> {
+    if let Some(&col) = indices.iter().find(|&&col| col >= self.cols()) {
+      return Err(Error::MatrixIndexOutOfBounds { index: MatrixIndex::Column(col), shape: self.shape() });
+    }
+
+    let mut data = Vec::with_capacity(self.rows() * indices.len());
+    for row in self.row_iter() {
+      data.extend(indices.iter().map(|&col| row[col]));
+    }
+
+    Ok(Matrix::from_parts(self.rows(), indices.len(), data))
+  }
+}
+
+impl<T: Float> VectorView<'_, T> {
+  /// Copy the entries listed in `indices`, in that order (repeats allowed)
+  pub fn select(&self, indices: &[usize]) -> Result<Vector<T>, Error> {
+    let data = indices.iter()
+      .map(|&idx| self.get(idx).ok_or(Error::IndexOutOfBounds { index: idx, len: self.len() }))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Vector { data })
+  }
+}
+
+impl<T: Float> Vector<T> {
+  /// Copy the entries listed in `indices`, in that order (repeats allowed)
+  pub fn select(&self, indices: &[usize]) -> Result<Vector<T>, Error> {
+    self.view().select(indices)
+  }
+}
+
+impl<T: Float> Matrix<T> {
+  /// Borrow row `row` as a slice
+  pub fn row(&self, row: usize) -> Option<&[T]> {
+    self.view().row(row)
+  }
+
+  /// Borrow row `row` as a mutable slice
+  pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+    if row >= self.rows() {
+      return None;
+    }
+
+    let cols = self.cols();
+    Some(&mut self.data_mut()[row * cols..(row + 1) * cols])
+  }
+
+  /// Iterate over the rows as slices
+  pub fn row_iter(&self) -> impl Iterator<Item = &[T]> {
+    self.view().row_iter()
+  }
+
+  /// Iterate over the entries of column `col`, top to bottom
+  pub fn column_iter(&self, col: usize) -> Option<impl Iterator<Item = T>> {
+    self.view().column_iter(col)
+  }
+
+  /// Copy column `col` into a Vector
+  pub fn column(&self, col: usize) -> Option<Vector<T>> {
+    self.view().column(col)
+  }
+
+  /// Borrow the contiguous rows in `rows` without copying
+  pub fn slice_rows(&self, rows: Range<usize>) -> Result<MatrixView<'_, T>, Error> {
+    self.view().slice_rows(rows)
+  }
+
+  /// Copy the block of `rows` x `cols`
+  pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>, Error> {
+    self.view().submatrix(rows, cols)
+  }
+
+  /// Copy the rows listed in `indices`, in that order (repeats allowed)
+  pub fn select_rows(&self, indices: &[usize]) -> Result<Matrix<T>, Error> {
+    self.view().select_rows(indices)
+  }
+
+  /// Copy the columns listed in `indices`, in that order (repeats allowed)
+  pub fn select_cols(&self, indices: &[usize]) -> Result<Matrix<T>, Error> {
+    self.view().select_cols(indices)
+  }
+
+  /// Place matrices with the same number of rows side by side
+  pub fn hstack(mats: &[MatrixView<T>]) -> Result<Matrix<T>, Error> {
+    let rows = mats.first().map_or(0, |m| m.rows());
+    if let Some(m) = mats.iter().find(|m| m.rows() != rows) {
+      return Err(Error::MatDimensionMismatch { left: mats[0].shape(), right: m.shape() });
+    }
+
+    let cols = mats.iter().map(|m| m.cols()).sum();
+    let mut data = Vec::with_capacity(rows * cols);
+    for row in 0..rows {
+      for mat in mats {
+        data.extend_from_slice(&mat.data()[row * mat.cols()..(row + 1) * mat.cols()]);
+      }
+    }
+
+    Ok(Matrix::from_parts(rows, cols, data))
+  }
+
+  /// Place matrices with the same number of columns on top of each other
+  pub fn vstack(mats: &[MatrixView<T>]) -> Result<Matrix<T>, Error> {
+    let cols = mats.first().map_or(0, |m| m.cols());
+    if let Some(m) = mats.iter().find(|m| m.cols() != cols) {
+      return Err(Error::MatDimensionMismatch { left: mats[0].shape(), right: m.shape() });
+    }
+
+    let rows = mats.iter().map(|m| m.rows()).sum();
+    let mut data = Vec::with_capacity(rows * cols);
+    for mat in mats {
+      data.extend_from_slice(mat.data());
+    }
+
+    Ok(Matrix::from_parts(rows, cols, data))
+  }
+}
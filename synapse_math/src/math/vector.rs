@@ -6,33 +6,33 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 19 May 2025
-/// Last Modified: 19 May 2025
+/// Last Modified: 17 October 2026
 
-use super::{scalar, Scalar};
+use super::{kernels, Float, Scalar, VectorView};
 
 use crate::error::Error;
 
 #[derive(Debug, Clone)]
-pub struct Vector {
-  pub data: Vec<Scalar>,
+pub struct Vector<T = Scalar> {
+  pub data: Vec<T>,
 }
 
-impl Vector {
-  pub fn new(len: usize) -> Vector {
+impl<T: Float> Vector<T> {
+  pub fn new(len: usize) -> Vector<T> {
     Vector {
-      data: vec![scalar::zero(); len]
+      data: vec![T::ZERO; len]
     }
   }
 
-  pub fn zeroes(len: usize) -> Vector {
+  pub fn zeroes(len: usize) -> Vector<T> {
     Vector {
-      data: vec![0.0; len]
+      data: vec![T::ZERO; len]
     }
   }
 
-  pub fn ones(len: usize) -> Vector {
+  pub fn ones(len: usize) -> Vector<T> {
     Vector {
-      data: vec![1.0; len]
+      data: vec![T::ONE; len]
     }
   }
 
@@ -44,49 +44,44 @@ impl Vector {
     self.data.is_empty()
   }
 
-  pub fn get(&self, idx: usize) -> Option<Scalar> {
-    match self.data.get(idx) {
-      Some(&value) => Some(value),
-      None => None,
-    }
+  pub fn get(&self, idx: usize) -> Option<T> {
+    self.data.get(idx).copied()
   }
 
-  pub fn set(&mut self, idx: usize, s: Scalar) -> Result<(), Error> {
+  pub fn set(&mut self, idx: usize, s: T) -> Result<(), Error> {
     if idx >= self.len() {
-      return Err(Error::IndexOutOfBounds);
+      return Err(Error::IndexOutOfBounds { index: idx, len: self.len() });
     }
 
     self.data[idx] = s;
     Ok(())
   }
 
-  pub fn dot(&self, other: &Vector) -> Result<Scalar, Error> {
+  pub fn dot(&self, other: &Vector<T>) -> Result<T, Error> {
     if self.len() != other.len() {
-      return Err(Error::VectorDimensionMismatch);
+      return Err(Error::VectorDimensionMismatch { left: self.len(), right: other.len() });
     }
 
-    Ok(self.data.iter()
-      .zip(&other.data)
-      .map(|(&a, &b)| scalar::mul(a, b))
-      .sum())
+    Ok(kernels::dot(&self.data, &other.data))
   }
 
-  pub fn scale(&self, s: Scalar) -> Vector {
+  pub fn scale(&self, s: T) -> Vector<T> {
 
     let len = self.len();
 
     let mut res_vec = Vector::zeroes(len);
 
     for idx in 0..len {
-      res_vec.data[idx] = scalar::mul(self.get(idx).unwrap(), s);
+      res_vec.data[idx] = self.get(idx).unwrap() * s;
     }
 
     res_vec
   }
 
-  pub fn add(&self, rhs: &Vector) -> Result<Vector, Error> {
+  /// Checked `self + rhs`, see `math::ops` for the operator form
+  pub fn add(&self, rhs: &Vector<T>) -> Result<Vector<T>, Error> {
     if self.len() != rhs.len() {
-      return Err(Error::VectorDimensionMismatch);
+      return Err(Error::VectorDimensionMismatch { left: self.len(), right: rhs.len() });
     }
 
     let mut res_vec = Vector::zeroes(self.len());
@@ -94,16 +89,17 @@ impl Vector {
     for idx in 0..self.len() {
       let a = self.get(idx).unwrap();
       let b = rhs.get(idx).unwrap();
-      let sum = scalar::add(a, b);
+      let sum = a + b;
       res_vec.set(idx, sum)?;
     }
 
     Ok(res_vec)
   }
 
-  pub fn sub(&self, rhs: &Vector) -> Result<Vector, Error> {
+  /// Checked `self - rhs`, see `math::ops` for the operator form
+  pub fn sub(&self, rhs: &Vector<T>) -> Result<Vector<T>, Error> {
     if self.len() != rhs.len() {
-      return Err(Error::VectorDimensionMismatch);
+      return Err(Error::VectorDimensionMismatch { left: self.len(), right: rhs.len() });
     }
 
     let mut res_vec = Vector::zeroes(self.len());
@@ -111,22 +107,50 @@ impl Vector {
     for idx in 0..self.len() {
       let a = self.get(idx).unwrap();
       let b = rhs.get(idx).unwrap();
-      let sum = scalar::sub(a, b);
+      let sum = a - b;
       res_vec.set(idx, sum)?;
     }
 
     Ok(res_vec)
   }
 
-  pub fn sum(&self) -> Scalar {
-    let mut sum = scalar::zero();
+  /// Same as `add`, named after the `+` operator it checks
+  pub fn try_add(&self, rhs: &Vector<T>) -> Result<Vector<T>, Error> {
+    self.add(rhs)
+  }
+
+  /// Same as `sub`, named after the `-` operator it checks
+  pub fn try_sub(&self, rhs: &Vector<T>) -> Result<Vector<T>, Error> {
+    self.sub(rhs)
+  }
+
+  pub fn sum(&self) -> T {
+    let mut sum = T::ZERO;
     for idx in 0..self.len() {
       sum += self.get(idx).unwrap();
     }
 
-    return sum;
+    sum
+  }
+
+  /// Apply `f` to every entry
+  pub fn map(&self, f: impl FnMut(T) -> T) -> Vector<T> {
+    self.view().map(f)
+  }
+
+  /// Apply `f` to every entry in place
+  pub fn map_inplace(&mut self, mut f: impl FnMut(T) -> T) {
+    self.data.iter_mut().for_each(|a| *a = f(*a));
+  }
+
+  /// Combine the matching entries of `self` and `other` with `f`
+  pub fn zip_map<'v>(
+    &self,
+    other: impl Into<VectorView<'v, T>>,
+    f: impl FnMut(T, T) -> T,
+  ) -> Result<Vector<T>, Error> {
+    self.view().zip_map(other.into(), f)
   }
 
-  // TODO: map function
   // TODO: vector mean and variance
 }
@@ -0,0 +1,204 @@
+/// math/view.rs - Math Engine borrowed Vector and Matrix views
+/// 
+/// This file defines VectorView and MatrixView, read only
+/// views over a borrowed Scalar buffer so data owned by
+/// C++ can be used in place without copying it
+/// 
+/// Like Matrix, MatrixView keeps its fields private so
+/// `data.len() == rows * cols` holds for every view and
+/// the Matrix copied out of it
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use super::{kernels, Float, Matrix, Scalar, Vector};
+
+use crate::error::{Error, MatrixIndex};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VectorView<'a, T = Scalar> {
+  pub data: &'a [T],
+}
+
+/// Row-major (rows, cols) view, see `MatrixView::new` and `Matrix::view`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MatrixView<'a, T = Scalar> {
+  rows: usize,
+  cols: usize,
+  data: &'a [T],
+}
+
+impl<'a, T: Float> VectorView<'a, T> {
+  pub fn new(data: &'a [T]) -> VectorView<'a, T> {
+    VectorView { data }
+  }
+
+  pub fn len(&self) -> usize {
+    self.data.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+
+  pub fn get(&self, idx: usize) -> Option<T> {
+    self.data.get(idx).copied()
+  }
+
+  pub fn dot(&self, other: VectorView<T>) -> Result<T, Error> {
+    if self.len() != other.len() {
+      return Err(Error::VectorDimensionMismatch { left: self.len(), right: other.len() });
+    }
+
+    Ok(kernels::dot(self.data, other.data))
+  }
+
+  /// Split into the views of [0, mid) and [mid, len)
+  pub fn split_at(&self, mid: usize) -> Result<(VectorView<'a, T>, VectorView<'a, T>), Error> {
+    if mid > self.len() {
+      return Err(Error::IndexOutOfBounds { index: mid, len: self.len() });
+    }
+
+    let (head, tail) = self.data.split_at(mid);
+    Ok((VectorView { data: head }, VectorView { data: tail }))
+  }
+
+  /// Apply `f` to every entry
+  pub fn map(&self, f: impl FnMut(T) -> T) -> Vector<T> {
+    Vector { data: self.data.iter().copied().map(f).collect() }
+  }
+
+  /// Combine the matching entries of `self` and `other` with `f`
+  pub fn zip_map(&self, other: VectorView<T>, mut f: impl FnMut(T, T) -> T) -> Result<Vector<T>, Error> {
+    if self.len() != other.len() {
+      return Err(Error::VectorDimensionMismatch { left: self.len(), right: other.len() });
+    }
+
+    Ok(Vector { data: self.data.iter().zip(other.data).map(|(&a, &b)| f(a, b)).collect() })
+  }
+
+  /// Copy the viewed data into an owned Vector
+  pub fn to_vector(&self) -> Vector<T> {
+    Vector { data: self.data.to_vec() }
+  }
+}
+
+impl<'a, T: Float> MatrixView<'a, T> {
+  /// Build a (rows, cols) view over a row-major buffer
+  pub fn new(rows: usize, cols: usize, data: &'a [T]) -> Result<MatrixView<'a, T>, Error> {
+    if rows.checked_mul(cols) != Some(data.len()) {
+      return Err(Error::MatDimensionMismatch { left: (rows, cols), right: (1, data.len()) });
+    }
+
+    Ok(MatrixView { rows, cols, data })
+  }
+
+  pub fn rows(&self) -> usize {
+    self.rows
+  }
+
+  pub fn cols(&self) -> usize {
+    self.cols
+  }
+
+  pub fn shape(&self) -> (usize, usize) {
+    (self.rows, self.cols)
+  }
+
+  /// Row-major entries, borrowed for the lifetime of the view
+  pub fn data(&self) -> &'a [T] {
+    self.data
+  }
+
+  pub fn get(&self, row: usize, col: usize) -> Option<T> {
+    if row >= self.rows || col >= self.cols {
+      return None;
+    }
+
+    self.data.get(row * self.cols + col).copied()
+  }
+
+  /// Borrow row `row` as a slice
+  pub fn row(&self, row: usize) -> Option<&'a [T]> {
+    if row >= self.rows {
+      return None;
+    }
+
+    Some(&self.data[row * self.cols..(row + 1) * self.cols])
+  }
+
+  /// Split into the views of rows [0, mid) and [mid, rows)
+  pub fn split_at_row(&self, mid: usize) -> Result<(MatrixView<'a, T>, MatrixView<'a, T>), Error> {
+    if mid > self.rows {
+      return Err(Error::MatrixIndexOutOfBounds { index: MatrixIndex::Row(mid), shape: self.shape() });
+    }
+
+    let (head, tail) = self.data.split_at(mid * self.cols);
+    Ok((
+      MatrixView { rows: mid, cols: self.cols, data: head },
+      MatrixView { rows: self.rows - mid, cols: self.cols, data: tail },
+    ))
+  }
+
+  pub fn transpose(&self) -> Matrix<T> {
+    let mut trans_mat = Matrix::zeros(self.cols, self.rows);
+    kernels::transpose(self.data, self.rows, self.cols, trans_mat.data_mut());
+
+    trans_mat
+  }
+
+  pub fn vec_mul(&self, vec: VectorView<T>) -> Result<Vector<T>, Error> {
+    if self.cols != vec.len() {
+      return Err(Error::MatDimensionMismatch { left: self.shape(), right: (vec.len(), 1) });
+    }
+
+    let mut res_vec = Vector::zeroes(self.rows);
+    kernels::gemv(self.data, vec.data, &mut res_vec.data);
+
+    Ok(res_vec)
+  }
+
+  /// Copy the viewed data into an owned Matrix
+  pub fn to_matrix(&self) -> Matrix<T> {
+    Matrix::from_parts(self.rows, self.cols, self.data.to_vec())
+  }
+}
+
+impl<'a, T> From<&'a Vector<T>> for VectorView<'a, T> {
+  fn from(vec: &'a Vector<T>) -> Self {
+    VectorView { data: &vec.data }
+  }
+}
+
+impl<'a, T: Copy> From<&VectorView<'a, T>> for VectorView<'a, T> {
+  fn from(view: &VectorView<'a, T>) -> Self {
+    *view
+  }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for MatrixView<'a, T> {
+  fn from(mat: &'a Matrix<T>) -> Self {
+    MatrixView { rows: mat.rows(), cols: mat.cols(), data: mat.data() }
+  }
+}
+
+impl<'a, T: Copy> From<&MatrixView<'a, T>> for MatrixView<'a, T> {
+  fn from(view: &MatrixView<'a, T>) -> Self {
+    *view
+  }
+}
+
+impl<T: Float> Vector<T> {
+  /// Borrow the vector as a VectorView
+  pub fn view(&self) -> VectorView<'_, T> {
+    VectorView::from(self)
+  }
+}
+
+impl<T: Float> Matrix<T> {
+  /// Borrow the matrix as a MatrixView
+  pub fn view(&self) -> MatrixView<'_, T> {
+    MatrixView::from(self)
+  }
+}
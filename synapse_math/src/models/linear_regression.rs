@@ -4,42 +4,45 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 23 May 2025
-/// Last Modified: 23 May 2025
+/// Last Modified: 17 October 2026
 
 use crate::rand::Random;
 use crate::solver::Solver;
-use crate::linear_algebra::MSE;
-use crate::math::{Scalar, Vector, Matrix};
+use crate::linear_algebra::gradient::{Optimizer, Regularization};
+use crate::math::{Float, Scalar, Vector, Matrix, MatrixView};
 use crate::ffi::InternalInput;
 use crate::error::Error;
 
-use super::Model;
+use super::{Model, ModelParams};
 
-pub struct LinearRegression {
-  pub weights: Vector,
-  pub bias: Scalar,
+/// Linear model trained and run in `T`, f64 unless built from f32 data
+pub struct LinearRegression<T = Scalar> {
+  pub weights: Vector<T>,
+  pub bias: T,
 }
 
-impl LinearRegression {
+impl<T> LinearRegression<T> {
   /// Standard deviation for initial weights/bias
   const INIT_STD: Scalar = 0.01;
 }
 
-impl Model for LinearRegression {
-  type LossFn = MSE;
+impl<T: Float> Model for LinearRegression<T> {
+  type Elem = T;
 
-  fn init(input: &InternalInput) -> Self {
-    let n_features = input.train_x.cols;
-    let mut rng = Random::new(None);
+  fn init(input: &InternalInput<T>) -> Result<Self, Error> {
+    let n_features = input.train_x.cols();
+    let mut rng = Random::new(input.seed);
+    // Drawn in f64 so a seed gives the same start whatever the element type
+    let weights = rng.normal_vector(n_features, 0.0, Self::INIT_STD)?;
 
-    LinearRegression {
-      weights: rng.normal_vector(n_features, 0.0, Self::INIT_STD),
-      bias: rng.normal_scalar(0.0, Self::INIT_STD),
-    }
+    Ok(LinearRegression {
+      weights: Vector { data: weights.data.into_iter().map(T::from_f64).collect() },
+      bias: T::from_f64(rng.normal_scalar(0.0, Self::INIT_STD)?),
+    })
   }
 
-  fn train(&mut self, input: &InternalInput) -> Result<(), Error> {
-    let mut solver = Solver::<Self>::new(input.clone());
+  fn train(&mut self, input: &InternalInput<T>) -> Result<(), Error> {
+    let mut solver = Solver::<Self>::new(*input)?;
     solver.train()?;
 
     self.weights = solver.model.weights;
@@ -48,41 +51,64 @@ impl Model for LinearRegression {
     Ok(())
   }
 
-  fn test(&self, input: &InternalInput) -> Result<Scalar, Error> {
-    let solver = Solver::<Self>::new(input.clone());
+  fn test(&self, input: &InternalInput<T>) -> Result<T, Error> {
+    let solver = Solver::<Self>::new(*input)?;
     solver.test()
   }
 
-  fn predict(&self, x: Matrix) -> Result<Vector, Error> {
-    // Compute X * weights (vector multiplication)
-    let mut out = x.vec_mul(&self.weights)?;
-    // Add bias term
-    for val in out.data.iter_mut() {
-      *val += self.bias;
-    }
-    Ok(out)
+  fn predict(&self, x: MatrixView<T>) -> Result<Vector<T>, Error> {
+    // X * weights with the bias broadcast over every output
+    Ok(x.vec_mul(self.weights.view())? + self.bias)
+  }
+
+  fn n_features(&self) -> usize {
+    self.weights.len()
   }
 
-  fn update(&mut self, x: &Matrix, grad_pred: &Vector, lr: Scalar) -> Result<(), Error> {
-    let batch_size = x.rows as Scalar;
-    let xt = x.transpose();
-    let mut grad_w = xt.vec_mul(grad_pred)?;
-    // Sum of per-example
-    let mut grad_b: Scalar = grad_pred.data.iter().copied().sum();
+  fn n_params(&self) -> usize {
+    self.weights.len() + 1
+  }
 
-    // Average over the batch
-    for gw in grad_w.data.iter_mut() {
-      *gw /= batch_size;
+  fn params(&self) -> ModelParams<T> {
+    ModelParams { weights: self.weights.clone(), bias: self.bias }
+  }
+
+  fn from_params(params: ModelParams<T>) -> Result<Self, Error> {
+    if params.weights.is_empty() {
+      return Err(Error::InvalidParameter("LinearRegression needs at least one weight".to_string()));
     }
-    grad_b /= batch_size;
 
-    // Gradient descent step
-    for idx in 0..self.weights.len() {
-      let w = self.weights.get(idx).unwrap();
-      let gw = grad_w.get(idx).unwrap();
-      self.weights.set(idx, w - lr * gw)?;
+    Ok(LinearRegression { weights: params.weights, bias: params.bias })
+  }
+
+  fn update(
+    &mut self,
+    x: &Matrix<T>,
+    grad_pred: &Vector<T>,
+    optimizer: &mut dyn Optimizer<T>,
+    reg: Regularization,
+  ) -> Result<(), Error> {
+    let batch_size = T::from_usize(x.rows());
+    // Per-example gradients averaged over the batch
+    let grad_w = x.transpose().vec_mul(grad_pred)? / batch_size;
+    let grad_b = grad_pred.sum() / batch_size;
+
+    // Pack [weights..., bias] so the optimizer sees a single vector,
+    // penalizing weights only
+    let n = self.weights.len();
+    let mut params = Vector::zeroes(n + 1);
+    let mut grad = Vector::zeroes(n + 1);
+    for idx in 0..n {
+      params[idx] = self.weights[idx];
+      grad[idx] = grad_w[idx] + reg.grad(self.weights[idx]);
     }
-    self.bias -= lr * grad_b;
+    params[n] = self.bias;
+    grad[n] = grad_b;
+
+    optimizer.update(&mut params, &grad)?;
+
+    self.weights.data.copy_from_slice(&params.data[..n]);
+    self.bias = params[n];
 
     Ok(())
   }
@@ -4,9 +4,10 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 22 May 2025
-/// Last Modified: 22 May 2025
+/// Last Modified: 17 October 2026
 
 pub mod model;
+pub mod registry;
 pub mod linear_regression;
 
-pub use model::Model;
+pub use model::{Model, DynModel, DynFit, ModelParams};
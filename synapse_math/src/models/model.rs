@@ -5,24 +5,119 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 23 May 2025
-/// Last Modified: 23 May 2025
+/// Last Modified: 17 October 2026
 
-use crate::linear_algebra::loss::Loss;
-use crate::math::{Matrix, Vector, Scalar};
+use std::any::Any;
+
+use crate::linear_algebra::gradient::{Optimizer, Regularization};
+use crate::math::{Float, Matrix, MatrixView, Vector, Scalar};
 use crate::ffi::InternalInput;
+use crate::solver::{EpochProgress, Solver, TrainingReport};
 use crate::error::Error;
 
-pub trait Model {
-  type LossFn: Loss;
+/// Learned parameters of a linear model, as stored in the JSON artifact
+#[derive(Debug, Clone)]
+pub struct ModelParams<T = Scalar> {
+  pub weights: Vector<T>,
+  pub bias: T,
+}
+
+impl<T: Float> ModelParams<T> {
+  /// Same parameters as f64, the element type of the artifacts
+  pub fn into_f64(self) -> ModelParams {
+    ModelParams {
+      weights: Vector { data: T::into_f64_vec(self.weights.data) },
+      bias: self.bias.to_f64(),
+    }
+  }
+}
+
+pub trait Model: Sized {
+  /// Element type the model is trained and run in
+  type Elem: Float;
 
   /// Build parameters and initializes the model
-  fn init(input: &InternalInput) -> Self;
+  fn init(input: &InternalInput<Self::Elem>) -> Result<Self, Error>;
   /// Run the full training loop
-  fn train(&mut self, input: &InternalInput) -> Result<(), Error>;
+  fn train(&mut self, input: &InternalInput<Self::Elem>) -> Result<(), Error>;
   /// Evaluate the chosen metric
-  fn test(&self, input: &InternalInput) -> Result<Scalar, Error>;
+  fn test(&self, input: &InternalInput<Self::Elem>) -> Result<Self::Elem, Error>;
   /// Run raw inference on *any* matrix of features
-  fn predict(&self, x: Matrix) -> Result<Vector, Error>;
-  /// Update model parameters
-  fn update(&mut self, x: &Matrix, grad_pred: &Vector, lr: Scalar) -> Result<(), Error>;
+  fn predict(&self, x: MatrixView<Self::Elem>) -> Result<Vector<Self::Elem>, Error>;
+  /// Number of input features the model expects
+  fn n_features(&self) -> usize;
+  /// Number of trainable parameters handed to the optimizer
+  fn n_params(&self) -> usize;
+  /// Copy of the learned parameters, used to export the model
+  fn params(&self) -> ModelParams<Self::Elem>;
+  /// Rebuild a trained model from exported parameters
+  fn from_params(params: ModelParams<Self::Elem>) -> Result<Self, Error>;
+  /// Update model parameters with `optimizer` from the gradient of
+  /// the loss w.r.t. the predictions, penalizing weights with `reg`
+  fn update(
+    &mut self,
+    x: &Matrix<Self::Elem>,
+    grad_pred: &Vector<Self::Elem>,
+    optimizer: &mut dyn Optimizer<Self::Elem>,
+    reg: Regularization,
+  ) -> Result<(), Error>;
+}
+
+/// Object safe view of a trained Model of any element type so models
+/// picked at runtime can be stored as `Box<dyn DynModel>`, taking and
+/// returning f64 data like the C API
+pub trait DynModel {
+  /// Run raw inference on *any* matrix of features
+  fn predict(&self, x: MatrixView) -> Result<Vector, Error>;
+  /// Number of input features the model expects
+  fn n_features(&self) -> usize;
+  /// Copy of the learned parameters, used to export the model
+  fn params(&self) -> ModelParams;
+  /// Access the concrete model, e.g. to read its parameters
+  fn as_any(&self) -> &dyn Any;
+}
+
+/// Object safe counterpart of Model training on `T` data, built
+/// at runtime by the registry as `Box<dyn DynFit<T>>`
+pub trait DynFit<T: Float = Scalar>: DynModel {
+  /// Train from the current parameters, reporting each epoch to
+  /// `on_epoch` which can return false to stop training, and return
+  /// the trained model with the report of the run
+  fn fit<'a>(
+    self: Box<Self>,
+    input: InternalInput<'a, T>,
+    on_epoch: &mut (dyn FnMut(&EpochProgress) -> bool + 'a),
+  ) -> Result<(Box<dyn DynModel>, TrainingReport), Error>;
+}
+
+impl<M: Model + 'static> DynModel for M {
+  fn predict(&self, x: MatrixView) -> Result<Vector, Error> {
+    let data = M::Elem::from_f64_slice(x.data());
+    let preds = Model::predict(self, MatrixView::new(x.rows(), x.cols(), &data)?)?;
+    Ok(Vector { data: M::Elem::into_f64_vec(preds.data) })
+  }
+
+  fn n_features(&self) -> usize {
+    Model::n_features(self)
+  }
+
+  fn params(&self) -> ModelParams {
+    Model::params(self).into_f64()
+  }
+
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+}
+
+impl<M: Model + 'static> DynFit<M::Elem> for M {
+  fn fit<'a>(
+    self: Box<Self>,
+    input: InternalInput<'a, M::Elem>,
+    on_epoch: &mut (dyn FnMut(&EpochProgress) -> bool + 'a),
+  ) -> Result<(Box<dyn DynModel>, TrainingReport), Error> {
+    let mut solver = Solver::with_model(*self, input)?.on_epoch(on_epoch);
+    let report = solver.train()?;
+    Ok((Box::new(solver.model), report))
+  }
 }
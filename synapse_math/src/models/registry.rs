@@ -0,0 +1,100 @@
+/// models/registry.rs - Math Engine Model registry
+/// 
+/// This file maps every ModelType to the model implementing
+/// it, adding a model only needs a new entry in `REGISTRY`
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use crate::error::Error;
+use crate::ffi::{Element, InternalInput, ModelType};
+use crate::linear_algebra::LossKind;
+use crate::math::Scalar;
+
+use super::linear_regression::LinearRegression;
+use super::{DynFit, Model, ModelParams};
+
+/// Builds an untrained boxed model from training input over `T`
+pub type ModelFactory<T = Scalar> = fn(&InternalInput<T>) -> Result<Box<dyn DynFit<T>>, Error>;
+/// Rebuilds a trained f64 boxed model from exported parameters
+pub type ModelLoader = fn(ModelParams) -> Result<Box<dyn DynFit>, Error>;
+
+pub struct ModelEntry {
+  pub model_type: ModelType,
+  /// Name used for the model in exported artifacts
+  pub name: &'static str,
+  /// Losses the model can be trained with
+  pub losses: &'static [LossKind],
+  pub init: ModelFactory,
+  pub init_f32: ModelFactory<f32>,
+  pub load: ModelLoader,
+}
+
+const REGISTRY: &[ModelEntry] = &[
+  ModelEntry {
+    model_type: ModelType::LinearRegression,
+    name: "LinearRegression",
+    losses: LINEAR_LOSSES,
+    init: init_boxed::<LinearRegression>,
+    init_f32: init_boxed::<LinearRegression<f32>>,
+    load: load_boxed::<LinearRegression>,
+  },
+  ModelEntry {
+    model_type: ModelType::MultiLinearRegression,
+    // Same model as LinearRegression, json_parser only knows that name
+    // so its artifacts load back as LinearRegression (see `load`)
+    name: "LinearRegression",
+    losses: LINEAR_LOSSES,
+    init: init_boxed::<LinearRegression>,
+    init_f32: init_boxed::<LinearRegression<f32>>,
+    load: load_boxed::<LinearRegression>,
+  },
+];
+
+/// Outputs are unbounded, cross entropy needs predictions in (0, 1]
+const LINEAR_LOSSES: &[LossKind] = &[LossKind::MSE];
+
+fn init_boxed<M: Model + 'static>(input: &InternalInput<M::Elem>) -> Result<Box<dyn DynFit<M::Elem>>, Error> {
+  Ok(Box::new(M::init(input)?))
+}
+
+fn load_boxed<M: Model<Elem = Scalar> + 'static>(params: ModelParams) -> Result<Box<dyn DynFit>, Error> {
+  Ok(Box::new(M::from_params(params)?))
+}
+
+/// Registry entry of `model_type`, if any model implements it
+pub fn lookup(model_type: ModelType) -> Option<&'static ModelEntry> {
+  REGISTRY.iter().find(|entry| entry.model_type == model_type)
+}
+
+/// First registry entry exported under `name`
+pub fn lookup_name(name: &str) -> Option<&'static ModelEntry> {
+  REGISTRY.iter().find(|entry| entry.name == name)
+}
+
+/// Build the untrained model registered for `model_type` over the
+/// element type of `input`, failing if it cannot be trained with `input.loss`
+pub fn create<T: Element>(model_type: ModelType, input: &InternalInput<T>) -> Result<Box<dyn DynFit<T>>, Error> {
+  let entry = lookup(model_type).ok_or_else(|| {
+    Error::InvalidParameter(format!("no model registered for {:?}", model_type))
+  })?;
+  if !entry.losses.contains(&input.loss) {
+    return Err(Error::InvalidParameter(format!(
+      "{:?} cannot be trained with {:?} loss, supported: {:?}",
+      model_type, input.loss, entry.losses
+    )));
+  }
+
+  T::factory(entry)(input)
+}
+
+/// Rebuild the trained model exported under `name`, as the first entry
+/// exported under it. Types sharing an artifact name come back as that
+/// entry's type, MultiLinearRegression models load as LinearRegression
+pub fn load(name: &str, params: ModelParams) -> Result<(ModelType, Box<dyn DynFit>), Error> {
+  match lookup_name(name) {
+    Some(entry) => Ok((entry.model_type, (entry.load)(params)?)),
+    None => Err(Error::InvalidInput(format!("unsupported model_type `{}`", name))),
+  }
+}
@@ -5,12 +5,14 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 21 May 2025
-/// Last Modified: 21 May 2025
+/// Last Modified: 17 October 2026
 
 use rand::{Rng, SeedableRng};
 use rand::rngs::{StdRng};
+use rand::seq::SliceRandom;
 use rand_distr::{Normal, Uniform};
 
+use crate::error::Error;
 use crate::math::{Matrix, Scalar, Vector};
 
 pub struct Random {
@@ -29,61 +31,84 @@ impl Random {
     Random { rng }
   }
 
+  /// Shuffle a slice in place
+  pub fn shuffle<T>(&mut self, items: &mut [T]) {
+    items.shuffle(&mut self.rng);
+  }
+
   /// Draw a single Scalar uniformaly in (low, high)
-  pub fn uniform_scalar(&mut self, low: Scalar, high: Scalar) -> Scalar {
-    self.rng.sample(Uniform::new(low, high).unwrap())
+  /// Fails if low >= high or a bound is not finite
+  pub fn uniform_scalar(&mut self, low: Scalar, high: Scalar) -> Result<Scalar, Error> {
+    let dist = Uniform::new(low, high).map_err(|_| Error::InvalidParameter(
+      format!("uniform range must be finite with low < high, got ({}, {})", low, high)
+    ))?;
+
+    Ok(self.rng.sample(dist))
   }
 
   /// Draw a single Scalar from Normal(mean, stddev)
-  pub fn normal_scalar(&mut self, mean: Scalar, std: Scalar) -> Scalar {
-    self.rng.sample(Normal::new(mean, std).unwrap())
+  /// Fails if mean is not finite, or std is negative or not finite
+  pub fn normal_scalar(&mut self, mean: Scalar, std: Scalar) -> Result<Scalar, Error> {
+    let invalid = || Error::InvalidParameter(
+      format!("normal mean and std must be finite with std >= 0, got ({}, {})", mean, std)
+    );
+
+    // rand_distr only rejects non finite std, a negative std or
+    // a non finite mean must be caught here
+    if !mean.is_finite() || std < 0.0 {
+      return Err(invalid());
+    }
+
+    let dist = Normal::new(mean, std).map_err(|_| invalid())?;
+
+    Ok(self.rng.sample(dist))
   }
 
   /// Create a vector of length len with entries Uniform(low, high)
-  pub fn uniform_vector(&mut self, len: usize, low: Scalar, high: Scalar) -> Vector {
+  pub fn uniform_vector(&mut self, len: usize, low: Scalar, high: Scalar) -> Result<Vector, Error> {
     let mut vec = Vector::zeroes(len);
 
     for idx in 0..len {
-      vec.set(idx, self.uniform_scalar(low, high)).unwrap();
+      vec.set(idx, self.uniform_scalar(low, high)?)?;
     }
 
-    vec
+    Ok(vec)
   }
 
   /// Create a vector of length len with entries Normal(mean, stddev)
-  pub fn normal_vector(&mut self, len: usize, mean: Scalar, std: Scalar) -> Vector {
+  pub fn normal_vector(&mut self, len: usize, mean: Scalar, std: Scalar) -> Result<Vector, Error> {
     let mut vec = Vector::zeroes(len);
 
     for idx in 0..len {
-      vec.set(idx, self.normal_scalar(mean, std)).unwrap();
+      vec.set(idx, self.normal_scalar(mean, std)?)?;
     }
 
-    vec
+    Ok(vec)
   }
 
   /// Draw a Matrix with shape (rows, cols) with Uniform entries
-  pub fn uniform_matrix(&mut self, rows: usize, cols: usize, low: Scalar, high: Scalar) -> Matrix {
+  pub fn uniform_matrix(&mut self, rows: usize, cols: usize, low: Scalar, high: Scalar) -> Result<Matrix, Error> {
     let mut mat = Matrix::zeros(rows, cols);
 
     for i in 0..rows {
       for j in 0..cols {
-        mat.set(i, j, self.uniform_scalar(low, high)).unwrap();
+        mat.set(i, j, self.uniform_scalar(low, high)?)?;
       }
     }
 
-    mat
+    Ok(mat)
   }
 
   /// Draw a Matrix with shape (rows, cols) with Normal entries
-  pub fn normal_matrix(&mut self, rows: usize, cols: usize, mean: Scalar, std: Scalar) -> Matrix {
+  pub fn normal_matrix(&mut self, rows: usize, cols: usize, mean: Scalar, std: Scalar) -> Result<Matrix, Error> {
     let mut mat = Matrix::zeros(rows, cols);
 
     for i in 0..rows {
       for j in 0..cols {
-        mat.set(i, j, self.normal_scalar(mean, std)).unwrap();
+        mat.set(i, j, self.normal_scalar(mean, std)?)?;
       }
     }
 
-    mat
+    Ok(mat)
   } 
 }
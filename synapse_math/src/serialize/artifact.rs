@@ -0,0 +1,193 @@
+/// serialize/artifact.rs - Math Engine Model artifact
+/// 
+/// This file defines the trained model artifact in
+/// the schema read by the json_parser (see json.h)
+/// and how it is saved and loaded
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::math::{Scalar, Vector};
+
+use super::json::{self, Value};
+
+/// Schema version written in every artifact
+pub const SCHEMA_VERSION: &str = "0.1.0";
+
+/// Trained model with the metadata of the run that produced it
+#[derive(Debug, Clone)]
+pub struct ModelArtifact {
+  pub schema_version: String,
+  pub run_id: String,
+  pub model_name: String,
+  pub model_type: String,
+  pub target: String,
+  pub epochs_trained: u32,
+  pub final_loss: Scalar,
+  pub weights: Vector,
+  pub bias: Scalar,
+}
+
+impl ModelArtifact {
+  /// Encode the artifact with the same key order and layout as tests/model.json
+  pub fn to_json(&self) -> Result<String, Error> {
+    let mut out = String::from("{\n");
+
+    let strings = [
+      ("schema_version", &self.schema_version),
+      ("run_id", &self.run_id),
+      ("model_name", &self.model_name),
+      ("model_type", &self.model_type),
+      ("target", &self.target),
+    ];
+    for (key, value) in strings {
+      out.push_str("  ");
+      json::write_string(&mut out, key);
+      out.push_str(": ");
+      json::write_string(&mut out, value);
+      out.push_str(",\n");
+    }
+
+    out.push_str(&format!("  \"epochs_trained\": {},\n", self.epochs_trained));
+    out.push_str("  \"final_loss\": ");
+    json::write_number(&mut out, "final_loss", self.final_loss)?;
+    out.push_str(",\n  \"weights\": ");
+    json::write_array(&mut out, "weights", &self.weights.data)?;
+    out.push_str(",\n  \"bias\": ");
+    json::write_number(&mut out, "bias", self.bias)?;
+    out.push_str("\n}\n");
+
+    Ok(out)
+  }
+
+  /// Write the artifact as JSON to `path`, replacing any existing file
+  pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+    let json = self.to_json()?;
+
+    fs::write(path, json)
+      .map_err(|err| Error::Io(format!("writing {}: {}", path.display(), err)))
+  }
+
+  /// Decode an artifact, every schema field is required and unknown
+  /// keys are rejected like the json_parser does
+  pub fn from_json(text: &str) -> Result<Self, Error> {
+    let Value::Object(members) = json::parse(text)? else {
+      return Err(Error::InvalidInput("model artifact must be a JSON object".to_string()));
+    };
+
+    let mut fields = Fields::default();
+    for (key, value) in members {
+      match key.as_str() {
+        "schema_version" => set(&mut fields.schema_version, &key, as_string(&key, value)?)?,
+        "run_id" => set(&mut fields.run_id, &key, as_string(&key, value)?)?,
+        "model_name" => set(&mut fields.model_name, &key, as_string(&key, value)?)?,
+        "model_type" => set(&mut fields.model_type, &key, as_string(&key, value)?)?,
+        "target" => set(&mut fields.target, &key, as_string(&key, value)?)?,
+        "epochs_trained" => set(&mut fields.epochs_trained, &key, as_epochs(&key, value)?)?,
+        "final_loss" => set(&mut fields.final_loss, &key, as_number(&key, value)?)?,
+        "weights" => set(&mut fields.weights, &key, as_weights(&key, value)?)?,
+        "bias" => set(&mut fields.bias, &key, as_number(&key, value)?)?,
+        _ => return Err(Error::InvalidInput(format!("unknown key `{}` in model artifact", key))),
+      }
+    }
+
+    let artifact = ModelArtifact {
+      schema_version: required(fields.schema_version, "schema_version")?,
+      run_id: required(fields.run_id, "run_id")?,
+      model_name: required(fields.model_name, "model_name")?,
+      model_type: required(fields.model_type, "model_type")?,
+      target: required(fields.target, "target")?,
+      epochs_trained: required(fields.epochs_trained, "epochs_trained")?,
+      final_loss: required(fields.final_loss, "final_loss")?,
+      weights: required(fields.weights, "weights")?,
+      bias: required(fields.bias, "bias")?,
+    };
+
+    if artifact.schema_version != SCHEMA_VERSION {
+      return Err(Error::InvalidInput(format!(
+        "unsupported schema_version `{}`, expected `{}`",
+        artifact.schema_version, SCHEMA_VERSION,
+      )));
+    }
+
+    Ok(artifact)
+  }
+
+  /// Read and decode the JSON artifact at `path`
+  pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)
+      .map_err(|err| Error::Io(format!("reading {}: {}", path.display(), err)))?;
+
+    Self::from_json(&text)
+  }
+}
+
+/// Artifact fields seen so far while decoding
+#[derive(Default)]
+struct Fields {
+  schema_version: Option<String>,
+  run_id: Option<String>,
+  model_name: Option<String>,
+  model_type: Option<String>,
+  target: Option<String>,
+  epochs_trained: Option<u32>,
+  final_loss: Option<Scalar>,
+  weights: Option<Vector>,
+  bias: Option<Scalar>,
+}
+
+fn set<T>(field: &mut Option<T>, key: &str, value: T) -> Result<(), Error> {
+  if field.replace(value).is_some() {
+    return Err(Error::InvalidInput(format!("duplicate key `{}` in model artifact", key)));
+  }
+  Ok(())
+}
+
+fn required<T>(field: Option<T>, key: &str) -> Result<T, Error> {
+  field.ok_or_else(|| Error::InvalidInput(format!("missing `{}` in model artifact", key)))
+}
+
+fn as_string(key: &str, value: Value) -> Result<String, Error> {
+  match value {
+    Value::String(s) => Ok(s),
+    _ => Err(Error::InvalidInput(format!("`{}` must be a string", key))),
+  }
+}
+
+fn as_number(key: &str, value: Value) -> Result<Scalar, Error> {
+  match value {
+    Value::Number(n) => Ok(n),
+    _ => Err(Error::InvalidInput(format!("`{}` must be a number", key))),
+  }
+}
+
+fn as_epochs(key: &str, value: Value) -> Result<u32, Error> {
+  let n = as_number(key, value)?;
+  if n.fract() != 0.0 || n < 0.0 || n > u32::MAX as Scalar {
+    return Err(Error::InvalidInput(format!("`{}` must be an unsigned integer, got {}", key, n)));
+  }
+  Ok(n as u32)
+}
+
+fn as_weights(key: &str, value: Value) -> Result<Vector, Error> {
+  let Value::Array(items) = value else {
+    return Err(Error::InvalidInput(format!("`{}` must be an array of numbers", key)));
+  };
+  if items.is_empty() {
+    return Err(Error::InvalidInput(format!("`{}` must not be empty", key)));
+  }
+
+  let data = items.into_iter()
+    .enumerate()
+    .map(|(idx, item)| as_number(&format!("{}[{}]", key, idx), item))
+    .collect::<Result<Vec<_>, _>>()?;
+
+  Ok(Vector { data })
+}
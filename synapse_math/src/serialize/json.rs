@@ -0,0 +1,329 @@
+/// serialize/json.rs - Math Engine JSON encoding
+/// 
+/// This file implements the small JSON writer and
+/// parser needed to save and load model artifacts
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use core::fmt::Write;
+
+use crate::error::Error;
+use crate::math::Scalar;
+
+/// Append `value` as a quoted JSON string
+pub fn write_string(out: &mut String, value: &str) {
+  out.push('"');
+  for c in value.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      c if c.is_control() => {
+        let _ = write!(out, "\\u{:04x}", c as u32);
+      }
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+}
+
+/// Append `value` as a JSON number, `field` names it in the error
+/// since NaN and infinities have no JSON representation
+pub fn write_number(out: &mut String, field: &str, value: Scalar) -> Result<(), Error> {
+  if !value.is_finite() {
+    return Err(Error::InvalidInput(format!("`{}` is not finite ({})", field, value)));
+  }
+
+  // Display prints the shortest form that parses back to the same value
+  let _ = write!(out, "{}", value);
+  Ok(())
+}
+
+/// Append `values` as a single line JSON array of numbers
+pub fn write_array(out: &mut String, field: &str, values: &[Scalar]) -> Result<(), Error> {
+  out.push('[');
+  for (idx, &value) in values.iter().enumerate() {
+    if idx > 0 {
+      out.push_str(", ");
+    }
+    if !value.is_finite() {
+      return Err(Error::InvalidInput(format!("`{}[{}]` is not finite ({})", field, idx, value)));
+    }
+    let _ = write!(out, "{}", value);
+  }
+  out.push(']');
+  Ok(())
+}
+
+
+/// Parsed JSON value, objects keep their keys in document order
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+  Null,
+  Bool(bool),
+  Number(Scalar),
+  String(String),
+  Array(Vec<Value>),
+  Object(Vec<(String, Value)>),
+}
+
+/// Deepest nesting of objects and arrays `parse` accepts, deeper
+/// documents are rejected instead of overflowing the stack
+pub const MAX_DEPTH: usize = 64;
+
+/// Parse a complete JSON document
+pub fn parse(text: &str) -> Result<Value, Error> {
+  let mut parser = Parser { bytes: text.as_bytes(), pos: 0, depth: 0 };
+  let value = parser.value()?;
+  parser.skip_whitespace();
+  if parser.pos < parser.bytes.len() {
+    return Err(parser.error("unexpected content after the JSON value"));
+  }
+  Ok(value)
+}
+
+struct Parser<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+  /// Objects and arrays currently open
+  depth: usize,
+}
+
+impl Parser<'_> {
+  fn error(&self, msg: &str) -> Error {
+    Error::InvalidInput(format!("JSON: {} at byte {}", msg, self.pos))
+  }
+
+  fn peek(&self) -> Option<u8> {
+    self.bytes.get(self.pos).copied()
+  }
+
+  fn skip_whitespace(&mut self) {
+    while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+      self.pos += 1;
+    }
+  }
+
+  fn expect(&mut self, byte: u8) -> Result<(), Error> {
+    self.skip_whitespace();
+    if self.peek() != Some(byte) {
+      return Err(self.error(&format!("expected '{}'", byte as char)));
+    }
+    self.pos += 1;
+    Ok(())
+  }
+
+  fn keyword(&mut self, word: &str, value: Value) -> Result<Value, Error> {
+    if !self.bytes[self.pos..].starts_with(word.as_bytes()) {
+      return Err(self.error("unexpected character"));
+    }
+    self.pos += word.len();
+    Ok(value)
+  }
+
+  fn value(&mut self) -> Result<Value, Error> {
+    self.skip_whitespace();
+    match self.peek() {
+      Some(b'{') => self.nested(Self::object),
+      Some(b'[') => self.nested(Self::array),
+      Some(b'"') => Ok(Value::String(self.string()?)),
+      Some(b'-' | b'0'..=b'9') => self.number(),
+      Some(b't') => self.keyword("true", Value::Bool(true)),
+      Some(b'f') => self.keyword("false", Value::Bool(false)),
+      Some(b'n') => self.keyword("null", Value::Null),
+      Some(_) => Err(self.error("unexpected character")),
+      None => Err(self.error("unexpected end of input")),
+    }
+  }
+
+  /// Parse an object or array with `parse`, one level deeper
+  fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Result<Value, Error>) -> Result<Value, Error> {
+    if self.depth == MAX_DEPTH {
+      return Err(self.error(&format!("nesting deeper than {} levels", MAX_DEPTH)));
+    }
+
+    self.depth += 1;
+    let value = parse(self);
+    self.depth -= 1;
+    value
+  }
+
+  fn object(&mut self) -> Result<Value, Error> {
+    self.expect(b'{')?;
+    let mut members = Vec::new();
+
+    self.skip_whitespace();
+    if self.peek() == Some(b'}') {
+      self.pos += 1;
+      return Ok(Value::Object(members));
+    }
+
+    loop {
+      self.skip_whitespace();
+      if self.peek() != Some(b'"') {
+        return Err(self.error("expected a string key"));
+      }
+      let key = self.string()?;
+      self.expect(b':')?;
+      members.push((key, self.value()?));
+
+      self.skip_whitespace();
+      match self.peek() {
+        Some(b',') => self.pos += 1,
+        Some(b'}') => {
+          self.pos += 1;
+          return Ok(Value::Object(members));
+        }
+        _ => return Err(self.error("expected ',' or '}'")),
+      }
+    }
+  }
+
+  fn array(&mut self) -> Result<Value, Error> {
+    self.expect(b'[')?;
+    let mut items = Vec::new();
+
+    self.skip_whitespace();
+    if self.peek() == Some(b']') {
+      self.pos += 1;
+      return Ok(Value::Array(items));
+    }
+
+    loop {
+      items.push(self.value()?);
+
+      self.skip_whitespace();
+      match self.peek() {
+        Some(b',') => self.pos += 1,
+        Some(b']') => {
+          self.pos += 1;
+          return Ok(Value::Array(items));
+        }
+        _ => return Err(self.error("expected ',' or ']'")),
+      }
+    }
+  }
+
+  fn number(&mut self) -> Result<Value, Error> {
+    let start = self.pos;
+    while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.peek() {
+      self.pos += 1;
+    }
+
+    // Input came from a &str and the scanned bytes are ASCII
+    let text = core::str::from_utf8(&self.bytes[start..self.pos]).unwrap_or_default();
+    match text.parse::<Scalar>() {
+      Ok(value) if value.is_finite() && is_json_number(text.as_bytes()) => Ok(Value::Number(value)),
+      _ => {
+        self.pos = start;
+        Err(self.error(&format!("invalid number `{}`", text)))
+      }
+    }
+  }
+
+  fn string(&mut self) -> Result<String, Error> {
+    self.expect(b'"')?;
+    let mut out = String::new();
+
+    loop {
+      let start = self.pos;
+      while let Some(byte) = self.peek() && byte != b'"' && byte != b'\\' && byte >= 0x20 {
+        self.pos += 1;
+      }
+      // Only splits on ASCII bytes so the run is valid UTF-8
+      out.push_str(core::str::from_utf8(&self.bytes[start..self.pos]).unwrap_or_default());
+
+      match self.peek() {
+        Some(b'"') => {
+          self.pos += 1;
+          return Ok(out);
+        }
+        Some(b'\\') => {
+          self.pos += 1;
+          out.push(self.escape()?);
+        }
+        Some(_) => return Err(self.error("control character in string")),
+        None => return Err(self.error("unterminated string")),
+      }
+    }
+  }
+
+  fn escape(&mut self) -> Result<char, Error> {
+    let Some(byte) = self.peek() else {
+      return Err(self.error("unterminated string"));
+    };
+    self.pos += 1;
+
+    match byte {
+      b'"' => Ok('"'),
+      b'\\' => Ok('\\'),
+      b'/' => Ok('/'),
+      b'b' => Ok('\u{8}'),
+      b'f' => Ok('\u{c}'),
+      b'n' => Ok('\n'),
+      b'r' => Ok('\r'),
+      b't' => Ok('\t'),
+      b'u' => {
+        let high = self.hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+          return char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape"));
+        }
+
+        // Characters outside the BMP are escaped as a surrogate pair
+        if !self.bytes[self.pos..].starts_with(b"\\u") {
+          return Err(self.error("unpaired surrogate"));
+        }
+        self.pos += 2;
+        let low = self.hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+          return Err(self.error("unpaired surrogate"));
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+          .ok_or_else(|| self.error("invalid unicode escape"))
+      }
+      _ => Err(self.error("invalid escape")),
+    }
+  }
+
+  fn hex4(&mut self) -> Result<u32, Error> {
+    let digits = self.bytes.get(self.pos..self.pos + 4).ok_or_else(|| self.error("truncated unicode escape"))?;
+    let text = core::str::from_utf8(digits).map_err(|_| self.error("invalid unicode escape"))?;
+    let code = u32::from_str_radix(text, 16).map_err(|_| self.error("invalid unicode escape"))?;
+    self.pos += 4;
+    Ok(code)
+  }
+}
+
+/// Whether `text` follows the JSON number grammar, which Rust's float
+/// parsing is looser than (`01`, `1.`, `.5` and `1.e5` are not JSON)
+fn is_json_number(text: &[u8]) -> bool {
+  let digits = |s: &[u8]| s.iter().take_while(|b| b.is_ascii_digit()).count();
+
+  let mut rest = text.strip_prefix(b"-").unwrap_or(text);
+  match rest.first() {
+    Some(b'0') => rest = &rest[1..],
+    Some(b'1'..=b'9') => rest = &rest[digits(rest)..],
+    _ => return false,
+  }
+  if let Some(frac) = rest.strip_prefix(b".") {
+    let n = digits(frac);
+    if n == 0 {
+      return false;
+    }
+    rest = &frac[n..];
+  }
+  if let Some(exp) = rest.strip_prefix(b"e").or_else(|| rest.strip_prefix(b"E")) {
+    let exp = exp.strip_prefix(b"+").or_else(|| exp.strip_prefix(b"-")).unwrap_or(exp);
+    let n = digits(exp);
+    if n == 0 {
+      return false;
+    }
+    rest = &exp[n..];
+  }
+
+  rest.is_empty()
+}
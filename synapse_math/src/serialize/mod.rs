@@ -0,0 +1,14 @@
+/// serialize/mod.rs - Math Engine Serialize module
+/// 
+/// This module exposes the model artifact written
+/// for the json_parser and its JSON encoding
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+
+pub mod json;
+pub mod artifact;
+
+pub use artifact::{ModelArtifact, SCHEMA_VERSION};
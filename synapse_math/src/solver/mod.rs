@@ -4,10 +4,12 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 23 May 2025
-/// Last Modified: 23 May 2025
+/// Last Modified: 17 October 2026
 
 pub mod solver;
+pub mod report;
 pub mod solver_utils;
 
-pub use solver::Solver;
+pub use solver::{Solver, EpochProgress, EpochObserver};
+pub use report::{StopReason, TrainingReport};
 pub use solver_utils::{shuffle_indices, batches, should_stop};
@@ -0,0 +1,47 @@
+/// solver/report.rs - Math Engine Training report
+/// 
+/// This file defines the report returned by a training
+/// run with its loss curves and why it stopped
+/// 
+/// Author: Fedi Nabli
+/// Date: 17 October 2026
+/// Last Modified: 17 October 2026
+
+use std::time::Duration;
+
+use crate::math::Scalar;
+
+/// Why the training loop ended
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StopReason {
+  /// Ran every requested epoch
+  #[default]
+  Finished = 0,
+  /// Validation loss stopped improving for `early_stop` epochs
+  EarlyStop = 1,
+  /// A loss became NaN or infinite
+  Diverged = 2,
+  /// The progress observer asked to stop
+  Cancelled = 3,
+}
+
+/// Outcome of a training run, kept alongside the trained model
+#[derive(Debug, Clone, Default)]
+pub struct TrainingReport {
+  /// Epochs actually run, less than requested if stopped early
+  pub epochs_trained: u32,
+  /// Mean training loss of each epoch run
+  pub train_loss: Vec<Scalar>,
+  /// Validation loss of each epoch run
+  pub val_loss: Vec<Scalar>,
+  /// Epoch (1 based) with the lowest validation loss, 0 if none
+  pub best_epoch: u32,
+  /// Validation loss at `best_epoch`
+  pub best_val_loss: Scalar,
+  /// Loss of the trained model on the test set
+  pub final_loss: Scalar,
+  pub stop_reason: StopReason,
+  /// Wall clock time spent training
+  pub elapsed: Duration,
+}
@@ -4,59 +4,131 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 23 May 2025
-/// Last Modified: 23 May 2025
+/// Last Modified: 17 October 2026
+
+use std::time::Instant;
 
 use crate::error::Error;
-use crate::linear_algebra::loss::Loss;
-use crate::math::Scalar;
+use crate::math::{Float, Scalar};
 use crate::models::Model;
+use crate::rand::Random;
 use crate::ffi::InternalInput;
 
+use super::report::{StopReason, TrainingReport};
 use super::solver_utils;
 
-pub struct Solver<M: Model> {
+/// Losses reported to the progress observer after each epoch
+#[derive(Debug, Clone, Copy)]
+pub struct EpochProgress {
+  pub epoch: u32,
+  pub train_loss: Scalar,
+  pub val_loss: Scalar,
+}
+
+/// Called after each epoch, returning false cancels training
+pub type EpochObserver<'a> = Box<dyn FnMut(&EpochProgress) -> bool + 'a>;
+
+pub struct Solver<'a, M: Model> {
   pub model: M,
-  pub input: InternalInput,
+  pub input: InternalInput<'a, M::Elem>,
+  rng: Random,
+  on_epoch: Option<EpochObserver<'a>>,
 }
 
-impl<M: Model> Solver<M> {
+impl<'a, M: Model> Solver<'a, M> {
   /// Initialize solver and model parameters
-  pub fn new(input: InternalInput) -> Self {
-    let model = M::init(&input);
-    Solver { model, input }
+  pub fn new(input: InternalInput<'a, M::Elem>) -> Result<Self, Error> {
+    let model = M::init(&input)?;
+    Self::with_model(model, input)
+  }
+
+  /// Train an already initialized `model`, which must expect
+  /// as many features as `input` has columns
+  pub fn with_model(model: M, input: InternalInput<'a, M::Elem>) -> Result<Self, Error> {
+    if model.n_features() != input.train_x.cols() {
+      return Err(Error::MatDimensionMismatch {
+        left: input.train_x.shape(),
+        right: (model.n_features(), 1),
+      });
+    }
+
+    let rng = Random::new(input.seed);
+    Ok(Solver { model, input, rng, on_epoch: None })
+  }
+
+  /// Report progress to `observer` after each epoch, training
+  /// stops early as soon as it returns false
+  pub fn on_epoch(mut self, observer: impl FnMut(&EpochProgress) -> bool + 'a) -> Self {
+    self.on_epoch = Some(Box::new(observer));
+    self
   }
 
-  /// Run full training loop with batching, loss tracking and early stopping
-  pub fn train(&mut self) -> Result<(), Error> {
+  /// Run full training loop with batching, loss tracking and early stopping,
+  /// reporting the loss curves and why training stopped
+  /// 
+  /// The model is trained in its element type, only the
+  /// reported losses are widened to f64
+  pub fn train(&mut self) -> Result<TrainingReport, Error> {
+    let start = Instant::now();
+    let mut report = TrainingReport::default();
     let mut best_loss: Option<Scalar> = None;
     let mut no_improve = 0;
     let patience = self.input.early_stop;
+    let loss = self.input.loss;
+
+    let ((train_x, train_y), (val_x, val_y)) = self.input.validation_split()?;
+    let n_train = M::Elem::from_usize(train_x.rows());
+    let learning_rate = M::Elem::from_f64(self.input.learning_rate);
+    let mut optimizer = self.input.optimizer.build(learning_rate, self.model.n_params());
 
     for epoch in 1..=self.input.epochs {
       // Shuffle & batch training data
       let batches = solver_utils::batches(
-        &self.input.train_x,
-        &self.input.train_y,
+        train_x,
+        train_y,
         self.input.batch_size as usize,
-      );
+        &mut self.rng,
+      )?;
 
+      let mut train_loss = M::Elem::ZERO;
       for (batch_x, batch_y) in batches {
         // Forward pass: predictions
-        let preds = self.model.predict(batch_x.clone())?;
+        let preds = self.model.predict(batch_x.view())?;
+        // Batch losses weighted by their size average to the epoch loss
+        train_loss += loss.loss(&preds, &batch_y)? * M::Elem::from_usize(batch_y.len()) / n_train;
         // Backward pass: gradient of loss
-        let grad = M::LossFn::grad(&preds, &batch_y)?;
+        let grad = loss.grad(&preds, &batch_y)?;
         // Update model parameters based on gradient
-        self.model.update(&batch_x, &grad, self.input.learning_rate)?;
+        self.model.update(&batch_x, &grad, optimizer.as_mut(), self.input.regularization)?;
+      }
+
+      // Validation: compute loss on the held out rows or the test set
+      let val_preds = self.model.predict(val_x)?;
+      let val_loss = loss.loss(&val_preds, val_y)?;
+      let (train_loss, val_loss) = (train_loss.to_f64(), val_loss.to_f64());
+
+      report.epochs_trained = epoch;
+      report.train_loss.push(train_loss);
+      report.val_loss.push(val_loss);
+      if report.best_epoch == 0 || val_loss < report.best_val_loss {
+        report.best_epoch = epoch;
+        report.best_val_loss = val_loss;
       }
 
-      // Validation: compute loss on test set
-      let val_preds = self.model.predict(self.input.test_x.clone())?;
-      let val_loss = M::LossFn::loss(&val_preds, &self.input.test_y)?;
-      println!("Epoch {}: validation loss = {}", epoch, val_loss);
+      let progress = EpochProgress { epoch, train_loss, val_loss };
+      if let Some(observer) = self.on_epoch.as_mut() && !observer(&progress) {
+        report.stop_reason = StopReason::Cancelled;
+        break;
+      }
+
+      if !train_loss.is_finite() || !val_loss.is_finite() {
+        report.stop_reason = StopReason::Diverged;
+        break;
+      }
 
       // Early stopping check
       if solver_utils::should_stop(best_loss, val_loss, patience, &mut no_improve) {
-        println!("Early stopping triggered at epoch {}", epoch);
+        report.stop_reason = StopReason::EarlyStop;
         break;
       }
 
@@ -66,12 +138,14 @@ impl<M: Model> Solver<M> {
       });
     }
 
-    Ok(())
+    report.final_loss = self.test()?.to_f64();
+    report.elapsed = start.elapsed();
+    Ok(report)
   }
 
   /// Evaluate the model on the test set, returning the final loss
-  pub fn test(&self) -> Result<Scalar, Error> {
-    let preds = self.model.predict(self.input.test_x.clone())?;
-    M::LossFn::loss(&preds, &self.input.test_y)
+  pub fn test(&self) -> Result<M::Elem, Error> {
+    let preds = self.model.predict(self.input.test_x)?;
+    self.input.loss.loss(&preds, self.input.test_y)
   }
 }
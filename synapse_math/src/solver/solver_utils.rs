@@ -5,42 +5,50 @@
 /// 
 /// Author: Fedi Nabli
 /// Date: 23 May 2025
-/// Last Modified: 23 May 2025
+/// Last Modified: 17 October 2026
 
-use rand::seq::SliceRandom;
-
-use crate::math::{Matrix, Scalar, Vector};
+use crate::error::Error;
+use crate::math::{Float, Matrix, MatrixView, Scalar, Vector, VectorView};
+use crate::rand::Random;
 
 /// Return a random permutation
-pub fn shuffle_indices(n: usize) -> Vec<usize> {
+pub fn shuffle_indices(n: usize, rng: &mut Random) -> Vec<usize> {
   let mut idx: Vec<usize> = (0..n).collect();
-  idx.shuffle(&mut rand::rng());
+  rng.shuffle(&mut idx);
   idx
 }
 
-/// Split X and Y into mini batches of size `batch_size`
-pub fn batches(x: &Matrix, y: &Vector, batch_size: usize) -> Vec<(Matrix, Vector)> {
-  let n = x.rows;
-  let idx = shuffle_indices(n);
-
-  idx.chunks(batch_size)
-    .map(|chunk| {
-      let mut bx = Vec::with_capacity(chunk.len() * x.cols);
-      let mut by = Vec::with_capacity(chunk.len());
+/// Split X and Y into shuffled mini batches of size `batch_size`
+/// 
+/// Batches are built lazily so only one batch is copied out
+/// of the borrowed data at a time
+pub fn batches<'a, T: Float>(
+  x: MatrixView<'a, T>,
+  y: VectorView<'a, T>,
+  batch_size: usize,
+  rng: &mut Random,
+) -> Result<impl Iterator<Item = (Matrix<T>, Vector<T>)> + use<'a, T>, Error> {
+  if batch_size == 0 {
+    return Err(Error::InvalidParameter("batch size must be greater than 0".to_string()));
+  }
+  if x.rows() != y.len() {
+    return Err(Error::MatDimensionMismatch { left: x.shape(), right: (y.len(), 1) });
+  }
 
-      for &i in chunk {
-        let start = i * x.cols;
-        let end = start + x.cols;
-        bx.extend_from_slice(&x.data[start..end]);
-        by.push(y.data[i]);
-      }
+  let n = x.rows();
+  let idx = shuffle_indices(n, rng);
 
+  let batches = (0..n).step_by(batch_size)
+    .map(move |first| {
+      let chunk = &idx[first..n.min(first + batch_size)];
+      // Every index comes from the permutation of 0..n so selection can't fail
       (
-        Matrix { rows: chunk.len(), cols: x.cols, data: bx },
-        Vector { data: by },
+        x.select_rows(chunk).expect("batch row index in bounds"),
+        y.select(chunk).expect("batch index in bounds"),
       )
-    })
-    .collect()
+    });
+
+  Ok(batches)
 }
 
 /// Return true if we've gone `patience` epochs without improvement
@@ -40,7 +40,7 @@ pub fn variance(vec: &Vector, ddof: usize) -> Result<Scalar, Error> {
 /// Standard Deviation
 /// std = sqrt(variance)
 pub fn std_dev(var: Scalar) -> Scalar {
-  return sqrt(var);
+  sqrt(var)
 }
 
 /// Z-score normalization (Xi - mean) / std_dev
@@ -51,7 +51,7 @@ pub fn normalize(vec: &Vector, ddof: usize) -> Result<Vector, Error> {
     .map(|&x| (x - avg) / sd)
     .collect();
 
-  Ok(Vector { data: data })
+  Ok(Vector { data })
 }
 
 /// Covariance between 2 datasets